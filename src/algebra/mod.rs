@@ -20,6 +20,30 @@ pub trait Affine<U, F> {
     fn affine(x: Self, u: U) -> Self;
 }
 
+/// Trivial monoid, e.g. no action.
+impl Monoid<()> for () {
+    const ID: Self = ();
+    #[inline]
+    fn binop(_: (), _: ()) {}
+}
+/// Trivial action, for structures whose action is optional.
+impl<T> Affine<(), ()> for T {
+    #[inline]
+    fn affine(x: Self, _: ()) -> Self {
+        x
+    }
+}
+/// Reversed `M`, i.e. `binop(x, y)` as `M`'s `binop(y, x)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Rev<M>(std::marker::PhantomData<M>);
+impl<T: Monoid<M>, M> Monoid<Rev<M>> for T {
+    const ID: Self = T::ID;
+    #[inline]
+    fn binop(x: Self, y: Self) -> Self {
+        T::binop(y, x)
+    }
+}
+
 macro_rules! monoid {
     (impl $a:ident for $t:ty, $e:expr, |$x:ident, $y:ident| $b:expr) => {
         impl Monoid<$a> for $t {
@@ -73,6 +73,12 @@ impl<V: Ord + Clone> OrdIndex<V> {
             a: a.into_boxed_slice(),
         }
     }
+    /// Count of values `< v`, i.e. id of first value `>= v`.
+    pub fn lower_bound(&self, v: &V) -> usize {
+        match self.a.binary_search(v) {
+            Ok(i) | Err(i) => i,
+        }
+    }
 }
 impl<V: Ord + Copy> IndexMap for OrdIndex<V> {
     type Val = V;
@@ -0,0 +1,62 @@
+use super::sparse_table::msb;
+use crate::algebra::Monoid;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// For static range query of any monoid, not necessarily idempotent or commutative.
+///
+/// Level `k` splits into blocks of `2^k`, stores suffix/prefix sums towards each block's middle.
+///
+/// # Time complexity
+///
+/// Build *O*(*n* log *n*), query *O*(1).
+#[derive(Clone, Debug)]
+pub struct DisjointSparseTable<T, M> {
+    st: Vec<Vec<T>>,
+    _m: PhantomData<M>,
+}
+
+impl<T, M> DisjointSparseTable<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    pub fn new(a: &[T]) -> Self {
+        let n = a.len();
+        let L = msb(n.max(1).next_power_of_two());
+        let mut st = vec![Vec::from(a); L + 1];
+        for (k, row) in st.iter_mut().enumerate().skip(1) {
+            let h = 1 << (k - 1);
+            for s in (0..n).step_by(h << 1) {
+                let m = s + h;
+                if m >= n {
+                    break;
+                }
+                for i in (s..m - 1).rev() {
+                    row[i] = T::binop(a[i], row[i + 1]);
+                }
+                for i in m + 1..n.min(m + h) {
+                    row[i] = T::binop(row[i - 1], a[i]);
+                }
+            }
+        }
+        Self {
+            st,
+            _m: PhantomData,
+        }
+    }
+    pub fn query(&self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        if l >= r {
+            return T::ID;
+        }
+        let r = r - 1;
+        if l == r {
+            return self.st[0][l];
+        }
+        let k = msb(l ^ r) + 1;
+        T::binop(self.st[k][l], self.st[k][r])
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+
+#[test]
+fn add() {
+    let rng = Rng::new();
+    for n in 0..70 {
+        let a: Vec<i64> = (0..n).map(|_| (rng.gen() % 1000) as i64 - 500).collect();
+        let st = DisjointSparseTable::<_, Add>::new(&a);
+        for l in 0..=n {
+            for r in l..=n {
+                assert_eq!(st.query(l..r), a[l..r].iter().sum::<i64>());
+            }
+        }
+    }
+}
+
+// x -> ax+b mod p, composed left to right.
+#[test]
+fn affine_compose() {
+    const P: i64 = 998_244_353;
+    monoid_new!(impl Compose for (i64, i64), (1, 0), |f, g| (f.0 * g.0 % P, (f.1 * g.0 + g.1) % P));
+    let rng = Rng::new();
+    let n = 100;
+    let a: Vec<(i64, i64)> = (0..n)
+        .map(|_| ((rng.gen() % P as u64) as i64, (rng.gen() % P as u64) as i64))
+        .collect();
+    let st = DisjointSparseTable::<_, Compose>::new(&a);
+    for l in 0..=n {
+        for r in l..=n {
+            let res = a[l..r]
+                .iter()
+                .fold((1, 0), |f, &g| <(i64, i64) as Monoid<Compose>>::binop(f, g));
+            assert_eq!(st.query(l..r), res);
+        }
+    }
+}
@@ -1,14 +1,34 @@
+use crate::algebra::Monoid;
+use std::marker::PhantomData;
+
 /// Classic.
-pub struct Dsu {
+pub struct Dsu<T = (), M = ()> {
     p: Vec<usize>,
     r: Vec<usize>,
+    v: Vec<T>,
+    c: usize,
+    _m: PhantomData<M>,
 }
 
 impl Dsu {
     pub fn new(n: usize) -> Self {
+        Self::from(&vec![(); n])
+    }
+}
+
+impl<T, M> Dsu<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// Each `x` starts with value `a[x]`, require commutativity.
+    pub fn from(a: &[T]) -> Self {
+        let n = a.len();
         Self {
             p: (0..n).collect(),
             r: vec![1; n],
+            v: Vec::from(a),
+            c: n,
+            _m: PhantomData,
         }
     }
     pub fn find(&mut self, x: usize) -> usize {
@@ -29,9 +49,30 @@ impl Dsu {
         }
         self.p[y] = x;
         self.r[x] += self.r[y];
+        self.v[x] = T::binop(self.v[x], self.v[y]);
+        self.c -= 1;
         false
     }
     pub fn check(&mut self, x: usize, y: usize) -> bool {
         self.find(x) == self.find(y)
     }
+    /// Size of component of `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let x = self.find(x);
+        self.r[x]
+    }
+    /// Aggregated value of component of `x`.
+    pub fn sum(&mut self, x: usize) -> T {
+        let x = self.find(x);
+        self.v[x]
+    }
+    /// Number of components.
+    pub fn components(&self) -> usize {
+        self.c
+    }
+    /// Members of each component, ordered by min member.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let root: Vec<_> = (0..self.p.len()).map(|x| self.find(x)).collect();
+        super::groups_of(&root)
+    }
 }
@@ -1,8 +1,33 @@
 //! Disjoint union find, for connection task.
+//!
+//! `Dsu`, `DsuRollBack`, `DsuPersistent` optionally aggregate a commutative `Monoid<M>` value
+//! per component, e.g. `Dsu::<i64, Add>::from(&a)`, default `()` for none.
 mod basic;
+mod offline;
 mod persistent;
 mod roll_back;
+mod weighted;
 
 pub use self::basic::Dsu;
+pub use self::offline::{dynamic_connectivity, ConnOp};
 pub use self::persistent::DsuPersistent;
 pub use self::roll_back::DsuRollBack;
+pub use self::weighted::{Contradiction, DsuWeighted};
+
+/// Members of each component, ordered by min member, by `root[x]`.
+fn groups_of(root: &[usize]) -> Vec<Vec<usize>> {
+    let n = root.len();
+    let mut id = vec![n; n];
+    let mut res: Vec<Vec<usize>> = vec![];
+    for (x, &r) in root.iter().enumerate() {
+        if id[r] == n {
+            id[r] = res.len();
+            res.push(vec![]);
+        }
+        res[id[r]].push(x);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+use super::DsuRollBack;
+use std::collections::HashMap;
+
+/// Event of dynamic connectivity, on undirected edge `(u, v)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnOp {
+    Add(usize, usize),
+    /// Remove one copy, must exist.
+    Remove(usize, usize),
+    Query(usize, usize),
+}
+
+/// Offline dynamic connectivity, ret: answer of each `Query` in order.
+///
+/// Each edge lives in an interval of time, put onto segment tree over time,
+/// then dfs with `DsuRollBack` join on enter, roll back on leave.
+///
+/// # Time complexity
+///
+/// *O*(*q* log *q* log *n*).
+pub fn dynamic_connectivity(n: usize, ops: &[ConnOp]) -> Vec<bool> {
+    let q = ops.len();
+    let N = q.next_power_of_two();
+    let mut seg = vec![vec![]; N << 1];
+    let mut alive: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    let live = |seg: &mut Vec<Vec<(usize, usize)>>, e, l: usize, r: usize| {
+        let (mut l, mut r) = (l + N, r + N);
+        while l < r {
+            if l & 1 == 1 {
+                seg[l].push(e);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                seg[r].push(e);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+    };
+    for (t, &op) in ops.iter().enumerate() {
+        match op {
+            ConnOp::Add(u, v) => {
+                alive.entry((u.min(v), u.max(v))).or_default().push(t);
+            }
+            ConnOp::Remove(u, v) => {
+                let e = (u.min(v), u.max(v));
+                let s = alive
+                    .get_mut(&e)
+                    .and_then(|s| s.pop())
+                    .expect("remove a non-existent edge");
+                live(&mut seg, e, s, t);
+            }
+            ConnOp::Query(..) => {}
+        }
+    }
+    for (e, ss) in alive {
+        for s in ss {
+            live(&mut seg, e, s, q);
+        }
+    }
+
+    fn dfs(
+        i: usize,
+        N: usize,
+        seg: &[Vec<(usize, usize)>],
+        ops: &[ConnOp],
+        dsu: &mut DsuRollBack,
+        res: &mut Vec<bool>,
+    ) {
+        if i >= N && i - N >= ops.len() {
+            return;
+        }
+        let snap = dsu.snapshot();
+        for &(u, v) in &seg[i] {
+            dsu.join(u, v);
+        }
+        if i >= N {
+            if let ConnOp::Query(u, v) = ops[i - N] {
+                res.push(dsu.check(u, v));
+            }
+        } else {
+            dfs(i << 1, N, seg, ops, dsu, res);
+            dfs(i << 1 | 1, N, seg, ops, dsu, res);
+        }
+        dsu.rollback_to(snap);
+    }
+    let mut res = vec![];
+    if q > 0 {
+        dfs(1, N, &seg, ops, &mut DsuRollBack::new(n), &mut res);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+use super::*;
+use crate::core::Rng;
+use crate::ds::dsu::Dsu;
+
+#[test]
+fn random() {
+    let rng = Rng::new();
+    for _ in 0..20 {
+        let n = 1 + rng.gen() as usize % 10;
+        let mut edges: Vec<(usize, usize)> = vec![];
+        let mut ops = vec![];
+        let mut expect = vec![];
+        for _ in 0..rng.gen() % 200 {
+            let u = rng.gen() as usize % n;
+            let v = rng.gen() as usize % n;
+            match rng.gen() % 3 {
+                0 => {
+                    edges.push((u, v));
+                    ops.push(ConnOp::Add(u, v));
+                }
+                1 if !edges.is_empty() => {
+                    let (u, v) = edges.swap_remove(rng.gen() as usize % edges.len());
+                    // either direction refers to same edge
+                    ops.push(ConnOp::Remove(v, u));
+                }
+                _ => {
+                    let mut d = Dsu::new(n);
+                    for &(x, y) in &edges {
+                        d.join(x, y);
+                    }
+                    expect.push(d.check(u, v));
+                    ops.push(ConnOp::Query(u, v));
+                }
+            }
+        }
+        assert_eq!(dynamic_connectivity(n, &ops), expect);
+    }
+}
@@ -1,18 +1,21 @@
 use std::fmt;
+use std::marker::PhantomData;
 
+use crate::algebra::Monoid;
 use crate::ds::ImVec;
 
 #[derive(Clone)]
-struct Node {
+struct Node<T> {
     p: usize,
     r: usize,
+    v: T,
 }
-impl fmt::Debug for Node {
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "p = {}, r = {}", self.p, self.r)
+        write!(f, "p = {}, r = {}, v = {:?}", self.p, self.r, self.v)
     }
 }
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 /// Immutable Dsu with ImVec implementation.
 ///
 /// # Example
@@ -26,17 +29,43 @@ impl fmt::Debug for Node {
 /// assert!(b.check(0, 2));
 /// assert!(!a.check(0, 2));
 /// ```
-pub struct DsuPersistent(ImVec<Node>);
+pub struct DsuPersistent<T = (), M = ()> {
+    a: ImVec<Node<T>>,
+    c: usize,
+    _m: PhantomData<M>,
+}
+
+impl<T: fmt::Debug + Clone, M> fmt::Debug for DsuPersistent<T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DsuPersistent").field(&self.a).finish()
+    }
+}
 
 impl DsuPersistent {
     pub fn new(n: usize) -> Self {
-        Self(ImVec::from_exact_sized_iter(
-            (0..n).map(|i| Node { p: i, r: 1 }),
-        ))
+        Self::from(&vec![(); n])
+    }
+}
+
+impl<T, M> DsuPersistent<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// Each `x` starts with value `a[x]`, require commutativity.
+    pub fn from(a: &[T]) -> Self {
+        Self {
+            a: ImVec::from_exact_sized_iter(a.iter().enumerate().map(|(i, &v)| Node {
+                p: i,
+                r: 1,
+                v,
+            })),
+            c: a.len(),
+            _m: PhantomData,
+        }
     }
     pub fn find(&self, mut x: usize) -> usize {
-        while x != self.0[x].p {
-            x = self.0[x].p;
+        while x != self.a[x].p {
+            x = self.a[x].p;
         }
         x
     }
@@ -47,14 +76,33 @@ impl DsuPersistent {
         if x == y {
             return true;
         }
-        if self.0[x].r < self.0[y].r {
+        if self.a[x].r < self.a[y].r {
             std::mem::swap(&mut x, &mut y);
         }
-        self.0[y].p = self.0[x].p;
-        self.0[x].r += self.0[y].r;
+        self.a[y].p = self.a[x].p;
+        self.a[x].r += self.a[y].r;
+        self.a[x].v = T::binop(self.a[x].v, self.a[y].v);
+        self.c -= 1;
         false
     }
     pub fn check(&self, x: usize, y: usize) -> bool {
         self.find(x) == self.find(y)
     }
+    /// Size of component of `x`.
+    pub fn size(&self, x: usize) -> usize {
+        self.a[self.find(x)].r
+    }
+    /// Aggregated value of component of `x`.
+    pub fn sum(&self, x: usize) -> T {
+        self.a[self.find(x)].v
+    }
+    /// Number of components.
+    pub fn components(&self) -> usize {
+        self.c
+    }
+    /// Members of each component, ordered by min member.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let root: Vec<_> = (0..self.a.len()).map(|x| self.find(x)).collect();
+        super::groups_of(&root)
+    }
 }
@@ -1,16 +1,39 @@
+use crate::algebra::Monoid;
+use std::marker::PhantomData;
+
 /// Without path compressing, to support roll back.
-pub struct DsuRollBack {
+///
+/// Every `join` is one step of history, including no-op ones.
+pub struct DsuRollBack<T = (), M = ()> {
     p: Vec<usize>,
     r: Vec<usize>,
-    h: Vec<[usize; 2]>,
+    v: Vec<T>,
+    c: usize,
+    /// `[x, y]` with old value of `x`, `None` for no-op join.
+    h: Vec<Option<([usize; 2], T)>>,
+    _m: PhantomData<M>,
 }
 
 impl DsuRollBack {
     pub fn new(n: usize) -> Self {
+        Self::from(&vec![(); n])
+    }
+}
+
+impl<T, M> DsuRollBack<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// Each `x` starts with value `a[x]`, require commutativity.
+    pub fn from(a: &[T]) -> Self {
+        let n = a.len();
         Self {
             p: (0..n).collect(),
             r: vec![1; n],
+            v: Vec::from(a),
+            c: n,
             h: vec![],
+            _m: PhantomData,
         }
     }
     pub fn find(&self, mut x: usize) -> usize {
@@ -24,25 +47,58 @@ impl DsuRollBack {
         x = self.find(x);
         y = self.find(y);
         if x == y {
+            self.h.push(None);
             return true;
         }
         if self.r[x] < self.r[y] {
             std::mem::swap(&mut x, &mut y);
         }
-        self.h.push([x, y]);
+        self.h.push(Some(([x, y], self.v[x])));
         self.p[y] = x;
         self.r[x] += self.r[y];
+        self.v[x] = T::binop(self.v[x], self.v[y]);
+        self.c -= 1;
         false
     }
     /// one step.
     pub fn roll_back(&mut self) {
-        if let Some([x, y]) = self.h.pop() {
+        if let Some(([x, y], v)) = self.h.pop().flatten() {
             self.r[x] -= self.r[y];
             self.p[y] = y;
+            self.v[x] = v;
+            self.c += 1;
+        }
+    }
+    /// Checkpoint, i.e. number of joins so far.
+    pub fn snapshot(&self) -> usize {
+        self.h.len()
+    }
+    /// Undo joins after `snapshot`.
+    pub fn rollback_to(&mut self, snapshot: usize) {
+        debug_assert!(snapshot <= self.h.len());
+        while self.h.len() > snapshot {
+            self.roll_back();
         }
     }
 
     pub fn check(&mut self, x: usize, y: usize) -> bool {
         self.find(x) == self.find(y)
     }
+    /// Size of component of `x`.
+    pub fn size(&self, x: usize) -> usize {
+        self.r[self.find(x)]
+    }
+    /// Aggregated value of component of `x`.
+    pub fn sum(&self, x: usize) -> T {
+        self.v[self.find(x)]
+    }
+    /// Number of components.
+    pub fn components(&self) -> usize {
+        self.c
+    }
+    /// Members of each component, ordered by min member.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let root: Vec<_> = (0..self.p.len()).map(|x| self.find(x)).collect();
+        super::groups_of(&root)
+    }
 }
@@ -0,0 +1,96 @@
+use super::*;
+use crate::algebra::Add;
+use crate::core::Rng;
+
+/// Brute force by relabel.
+fn brute(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut c: Vec<usize> = (0..n).collect();
+    for &(x, y) in edges {
+        let (a, b) = (c[x], c[y]);
+        for z in c.iter_mut() {
+            if *z == b {
+                *z = a;
+            }
+        }
+    }
+    c
+}
+
+#[test]
+fn query_surface() {
+    let rng = Rng::new();
+    let n = 40;
+    let a: Vec<i64> = (0..n).map(|_| (rng.gen() % 100) as i64).collect();
+    let mut d = Dsu::<i64, Add>::from(&a);
+    let mut e = DsuRollBack::<i64, Add>::from(&a);
+    let mut f = DsuPersistent::<i64, Add>::from(&a);
+    let mut edges = vec![];
+    for _ in 0..60 {
+        let x = rng.gen() as usize % n;
+        let y = rng.gen() as usize % n;
+        edges.push((x, y));
+        let ck = d.join(x, y);
+        assert_eq!(e.join(x, y), ck);
+        assert_eq!(f.join(x, y), ck);
+
+        let c = brute(n, &edges);
+        let comps = (0..n).filter(|&x| c[x] == x).count();
+        assert_eq!(d.components(), comps);
+        assert_eq!(e.components(), comps);
+        assert_eq!(f.components(), comps);
+        let groups = d.groups();
+        assert_eq!(groups.len(), comps);
+        assert_eq!(e.groups(), groups);
+        assert_eq!(f.groups(), groups);
+        for g in &groups {
+            assert!(g.iter().all(|&x| c[x] == c[g[0]]));
+        }
+        for x in 0..n {
+            let size = (0..n).filter(|&y| c[y] == c[x]).count();
+            let sum: i64 = (0..n).filter(|&y| c[y] == c[x]).map(|y| a[y]).sum();
+            assert_eq!(d.size(x), size);
+            assert_eq!(e.size(x), size);
+            assert_eq!(f.size(x), size);
+            assert_eq!(d.sum(x), sum);
+            assert_eq!(e.sum(x), sum);
+            assert_eq!(f.sum(x), sum);
+        }
+    }
+}
+
+#[test]
+fn roll_back_restores() {
+    let a: Vec<i64> = (1..=5).collect();
+    let mut e = DsuRollBack::<i64, Add>::from(&a);
+    e.join(0, 1);
+    e.join(2, 3);
+    e.join(1, 3);
+    assert_eq!((e.components(), e.size(0), e.sum(0)), (2, 4, 10));
+    e.roll_back();
+    assert_eq!((e.components(), e.size(0), e.sum(0)), (3, 2, 3));
+    assert_eq!(e.sum(3), 7);
+    assert_eq!(e.groups(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+}
+
+#[test]
+fn snapshot_counts_no_op() {
+    let mut e = DsuRollBack::new(4);
+    e.join(0, 1);
+    let s = e.snapshot();
+    assert!(e.join(1, 0));
+    e.join(2, 3);
+    assert_eq!(e.snapshot(), s + 2);
+    // undo exactly the last call, which merged
+    e.roll_back();
+    assert!(!e.check(2, 3));
+    // the no-op join is one step as well
+    e.roll_back();
+    assert!(e.check(0, 1));
+    e.join(1, 2);
+    e.join(2, 3);
+    assert_eq!(e.components(), 1);
+    e.rollback_to(s);
+    assert_eq!(e.groups(), vec![vec![0, 1], vec![2], vec![3]]);
+    e.rollback_to(0);
+    assert_eq!(e.components(), 4);
+}
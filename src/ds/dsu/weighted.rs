@@ -0,0 +1,93 @@
+use crate::algebra::Group;
+use std::marker::PhantomData;
+
+/// Returned by `DsuWeighted::join` when the new relation conflicts with known ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Contradiction;
+
+/// Dsu maintains relative value along edges, i.e. potential in a group.
+///
+/// Relation `diff(u, v) = w` means `x_v = x_u * w`, e.g. `x_v - x_u = w` for `Add`.
+/// Group needn't be commutative.
+///
+/// # Example
+///
+/// ```
+/// use cplib::algebra::Add;
+/// use cplib::ds::dsu::{Contradiction, DsuWeighted};
+///
+/// let mut d = DsuWeighted::<i64, Add>::new(3);
+/// assert_eq!(d.join(0, 1, 5), Ok(false));
+/// assert_eq!(d.join(1, 2, -2), Ok(false));
+/// assert_eq!(d.diff(0, 2), Some(3));
+/// assert_eq!(d.join(2, 0, -3), Ok(true));
+/// assert_eq!(d.join(2, 0, 0), Err(Contradiction));
+/// ```
+pub struct DsuWeighted<T, M> {
+    p: Vec<usize>,
+    r: Vec<usize>,
+    /// `x_i = x_{p_i} * w_i`.
+    w: Vec<T>,
+    _m: PhantomData<M>,
+}
+
+impl<T, M> DsuWeighted<T, M>
+where
+    T: Group<M> + Copy + PartialEq,
+{
+    pub fn new(n: usize) -> Self {
+        Self {
+            p: (0..n).collect(),
+            r: vec![1; n],
+            w: vec![T::ID; n],
+            _m: PhantomData,
+        }
+    }
+    pub fn find(&mut self, x: usize) -> usize {
+        let p = self.p[x];
+        if p != x {
+            self.p[x] = self.find(p);
+            self.w[x] = T::binop(self.w[p], self.w[x]);
+        }
+        self.p[x]
+    }
+    /// `diff` of root to `x`.
+    fn potential(&mut self, x: usize) -> T {
+        self.find(x);
+        self.w[x]
+    }
+    /// Add relation `x_v = x_u * w`, ret: check(u,v) before.
+    pub fn join(&mut self, u: usize, v: usize, w: T) -> Result<bool, Contradiction> {
+        let (mut x, mut y) = (self.find(u), self.find(v));
+        // x_y = x_x * w
+        let mut w = T::binop(T::binop(self.potential(u), w), T::inv(self.potential(v)));
+        if x == y {
+            return if w == T::ID {
+                Ok(true)
+            } else {
+                Err(Contradiction)
+            };
+        }
+        if self.r[x] < self.r[y] {
+            std::mem::swap(&mut x, &mut y);
+            w = T::inv(w);
+        }
+        self.p[y] = x;
+        self.r[x] += self.r[y];
+        self.w[y] = w;
+        Ok(false)
+    }
+    /// `w` with `x_v = x_u * w`, if `u`, `v` connected.
+    pub fn diff(&mut self, u: usize, v: usize) -> Option<T> {
+        if self.find(u) != self.find(v) {
+            return None;
+        }
+        Some(T::binop(T::inv(self.potential(u)), self.potential(v)))
+    }
+    pub fn check(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+}
+
+#[cfg(test)]
+mod tests;
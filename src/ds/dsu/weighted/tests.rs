@@ -0,0 +1,85 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+use crate::ds::dsu::Dsu;
+
+#[test]
+fn add_random() {
+    let rng = Rng::new();
+    let n = 50;
+    let x: Vec<i64> = (0..n).map(|_| (rng.gen() % 100) as i64).collect();
+    let mut d = DsuWeighted::<i64, Add>::new(n);
+    let mut e = Dsu::new(n);
+    for _ in 0..200 {
+        let u = rng.gen() as usize % n;
+        let v = rng.gen() as usize % n;
+        if e.check(u, v) {
+            assert_eq!(d.join(u, v, x[v] - x[u] + 1), Err(Contradiction));
+        }
+        assert_eq!(d.join(u, v, x[v] - x[u]), Ok(e.join(u, v)));
+        for _ in 0..5 {
+            let u = rng.gen() as usize % n;
+            let v = rng.gen() as usize % n;
+            let h = if e.check(u, v) {
+                Some(x[v] - x[u])
+            } else {
+                None
+            };
+            assert_eq!(d.diff(u, v), h);
+        }
+    }
+}
+
+#[test]
+fn xor_bipartite() {
+    // odd cycle 0-1-2 conflicts, even cycle 3-4-5-6 fine.
+    let mut d = DsuWeighted::<u32, Xor>::new(7);
+    assert_eq!(d.join(0, 1, 1), Ok(false));
+    assert_eq!(d.join(1, 2, 1), Ok(false));
+    assert_eq!(d.join(2, 0, 1), Err(Contradiction));
+    for &(u, v) in &[(3, 4), (4, 5), (5, 6)] {
+        assert_eq!(d.join(u, v, 1), Ok(false));
+    }
+    assert_eq!(d.join(6, 3, 1), Ok(true));
+    assert_eq!(d.diff(3, 5), Some(0));
+    assert_eq!(d.diff(0, 3), None);
+}
+
+monoid_new!(impl Perm for [usize; 3], [0, 1, 2], |p, q| [q[p[0]], q[p[1]], q[p[2]]]);
+impl Group<Perm> for [usize; 3] {
+    fn inv(p: Self) -> Self {
+        let mut q = [0; 3];
+        for i in 0..3 {
+            q[p[i]] = i;
+        }
+        q
+    }
+}
+
+#[test]
+fn non_commutative() {
+    let all = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let rng = Rng::new();
+    let n = 30;
+    let x: Vec<[usize; 3]> = (0..n).map(|_| all[rng.gen() as usize % 6]).collect();
+    let rel = |u: usize, v: usize| <[usize; 3] as Monoid<Perm>>::binop(Group::inv(x[u]), x[v]);
+    let mut d = DsuWeighted::<_, Perm>::new(n);
+    let mut e = Dsu::new(n);
+    for _ in 0..100 {
+        let u = rng.gen() as usize % n;
+        let v = rng.gen() as usize % n;
+        assert_eq!(d.join(u, v, rel(u, v)), Ok(e.join(u, v)));
+        let u = rng.gen() as usize % n;
+        let v = rng.gen() as usize % n;
+        if e.check(u, v) {
+            assert_eq!(d.diff(u, v), Some(rel(u, v)));
+        }
+    }
+}
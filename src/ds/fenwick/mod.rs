@@ -1,8 +1,12 @@
 //! A family of Fenwick Trees, called Binary Indexed Tree(BIT) also.
 
 mod range;
+mod set;
 mod tree;
+mod two_dim;
 
 pub use self::range::RangeAddPointGet;
 pub use self::range::RangeAddRangeSum;
+pub use self::set::{FenwickMultiset, FenwickSet};
 pub use self::tree::Fenwick;
+pub use self::two_dim::{Fenwick2d, Fenwick2dOffline};
@@ -0,0 +1,145 @@
+use super::Fenwick;
+use crate::algebra::Add;
+use crate::core::index_map::{IndexMap, OrdIndex};
+
+/// Ordered multiset on a universe given up front, by Fenwick of counts.
+///
+/// Queries accept any value, updates require value in universe.
+///
+/// # Time complexity
+///
+/// *O*(log *n*) per operation, `n` for size of universe.
+pub struct FenwickMultiset<V> {
+    idx: OrdIndex<V>,
+    fen: Fenwick<i64, Add>,
+    cnt: Vec<usize>,
+    len: usize,
+}
+
+impl<V: Ord + Copy> FenwickMultiset<V> {
+    pub fn new(universe: &[V]) -> Self {
+        let idx = OrdIndex::new(universe);
+        let n = idx.len();
+        Self {
+            idx,
+            fen: Fenwick::new(n),
+            cnt: vec![0; n],
+            len: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn insert(&mut self, x: V) {
+        let i = self.idx.id(x);
+        self.cnt[i] += 1;
+        self.len += 1;
+        self.fen.add(i, 1);
+    }
+    /// Remove one occurrence, ret: whether existed.
+    pub fn remove(&mut self, x: V) -> bool {
+        match self.find(&x) {
+            Some(i) if self.cnt[i] > 0 => {
+                self.cnt[i] -= 1;
+                self.len -= 1;
+                self.fen.add(i, -1);
+                true
+            }
+            _ => false,
+        }
+    }
+    pub fn count(&self, x: V) -> usize {
+        self.find(&x).map_or(0, |i| self.cnt[i])
+    }
+    /// Count of elements `< x`.
+    pub fn rank(&self, x: V) -> usize {
+        self.fen.prefix(self.idx.lower_bound(&x)) as usize
+    }
+    /// `k`-th(0-based) smallest element.
+    pub fn kth(&self, k: usize) -> Option<V> {
+        if k >= self.len {
+            return None;
+        }
+        Some(self.idx.nth(self.fen.binary_search(k as i64 + 1)))
+    }
+    /// Max element `< x`.
+    pub fn prev(&self, x: V) -> Option<V> {
+        match self.rank(x) {
+            0 => None,
+            r => self.kth(r - 1),
+        }
+    }
+    /// Min element `> x`.
+    pub fn next(&self, x: V) -> Option<V> {
+        let i = match self.find(&x) {
+            Some(i) => i + 1,
+            None => self.idx.lower_bound(&x),
+        };
+        self.kth(self.fen.prefix(i) as usize)
+    }
+
+    fn find(&self, x: &V) -> Option<usize> {
+        let i = self.idx.lower_bound(x);
+        if i < self.idx.len() && self.idx.nth(i) == *x {
+            Some(i)
+        } else {
+            None
+        }
+    }
+}
+
+/// Ordered set on a universe given up front, by Fenwick of counts.
+///
+/// # Time complexity
+///
+/// *O*(log *n*) per operation, `n` for size of universe.
+pub struct FenwickSet<V>(FenwickMultiset<V>);
+
+impl<V: Ord + Copy> FenwickSet<V> {
+    pub fn new(universe: &[V]) -> Self {
+        Self(FenwickMultiset::new(universe))
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// ret: whether newly inserted.
+    pub fn insert(&mut self, x: V) -> bool {
+        if self.contains(x) {
+            return false;
+        }
+        self.0.insert(x);
+        true
+    }
+    /// ret: whether existed.
+    pub fn remove(&mut self, x: V) -> bool {
+        self.0.remove(x)
+    }
+    pub fn contains(&self, x: V) -> bool {
+        self.0.count(x) > 0
+    }
+    /// Count of elements `< x`.
+    pub fn rank(&self, x: V) -> usize {
+        self.0.rank(x)
+    }
+    /// `k`-th(0-based) smallest element.
+    pub fn kth(&self, k: usize) -> Option<V> {
+        self.0.kth(k)
+    }
+    /// Max element `< x`.
+    pub fn prev(&self, x: V) -> Option<V> {
+        self.0.prev(x)
+    }
+    /// Min element `> x`.
+    pub fn next(&self, x: V) -> Option<V> {
+        self.0.next(x)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+// classic ordered set task, e.g. luogu p3369.
+use super::*;
+use crate::core::Rng;
+use std::collections::BTreeSet;
+
+#[test]
+fn multiset() {
+    let rng = Rng::new();
+    let universe: Vec<i32> = (0..100).map(|_| (rng.gen() % 1000) as i32 - 500).collect();
+    let mut s = FenwickMultiset::new(&universe);
+    let mut a: Vec<i32> = vec![];
+    for _ in 0..3000 {
+        let x = universe[rng.gen() as usize % universe.len()];
+        let y = (rng.gen() % 1100) as i32 - 550;
+        match rng.gen() % 3 {
+            0 => {
+                s.insert(x);
+                let i = a.iter().take_while(|&&v| v < x).count();
+                a.insert(i, x);
+            }
+            1 => {
+                let res = a.iter().position(|&v| v == x);
+                assert_eq!(s.remove(x), res.is_some());
+                res.map(|i| a.remove(i));
+            }
+            _ => {}
+        }
+        assert_eq!(s.len(), a.len());
+        assert_eq!(s.count(y), a.iter().filter(|&&v| v == y).count());
+        assert_eq!(s.rank(y), a.iter().filter(|&&v| v < y).count());
+        assert_eq!(s.prev(y), a.iter().rev().find(|&&v| v < y).copied());
+        assert_eq!(s.next(y), a.iter().find(|&&v| v > y).copied());
+        let k = rng.gen() as usize % (a.len() + 1);
+        assert_eq!(s.kth(k), a.get(k).copied());
+    }
+}
+
+#[test]
+fn set() {
+    let rng = Rng::new();
+    let universe: Vec<u64> = (0..50).map(|_| rng.gen() % 100).collect();
+    let mut s = FenwickSet::new(&universe);
+    let mut a = BTreeSet::new();
+    for _ in 0..1000 {
+        let x = universe[rng.gen() as usize % universe.len()];
+        if rng.gen() & 1 == 0 {
+            assert_eq!(s.insert(x), a.insert(x));
+        } else {
+            assert_eq!(s.remove(x), a.remove(&x));
+        }
+        let y = rng.gen() % 110;
+        assert_eq!(s.contains(y), a.contains(&y));
+        assert_eq!(s.rank(y), a.range(..y).count());
+        assert_eq!(s.prev(y), a.range(..y).next_back().copied());
+        assert_eq!(s.next(y), a.range(y + 1..).next().copied());
+        assert_eq!(s.kth(a.len() / 2), a.iter().nth(a.len() / 2).copied());
+    }
+}
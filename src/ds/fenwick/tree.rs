@@ -99,7 +99,7 @@ where
     }
 }
 #[inline]
-fn lsb(i: usize) -> usize {
+pub(super) fn lsb(i: usize) -> usize {
     (i as isize & (-(i as isize))) as usize
 }
 
@@ -0,0 +1,151 @@
+use super::tree::lsb;
+use super::Fenwick;
+use crate::algebra::{Group, Monoid};
+use crate::core::index_map::{IndexMap, OrdIndex};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// Dense 2D Fenwick tree, view as `a[0..n][0..m]`.
+///
+/// # Time complexity
+///
+/// *O*(log *n* log *m*) per operation, *O*(*nm*) space.
+#[derive(Clone, Debug)]
+pub struct Fenwick2d<T, M> {
+    n: usize,
+    m: usize,
+    v: Vec<T>,
+    _m: PhantomData<M>,
+}
+
+impl<T, M> Fenwick2d<T, M>
+where
+    T: Copy + Monoid<M>,
+{
+    /// Creates default view `a[0..n][0..m]`, filled with Monoid Identity.
+    pub fn new(n: usize, m: usize) -> Self {
+        Self {
+            n,
+            m,
+            v: vec![T::ID; (n + 1) * (m + 1)],
+            _m: PhantomData,
+        }
+    }
+    /// Behave as `a[i][j] += x`.
+    pub fn add(&mut self, i: usize, j: usize, x: T) {
+        let mut i = i + 1;
+        while i <= self.n {
+            let mut j = j + 1;
+            while j <= self.m {
+                let k = i * (self.m + 1) + j;
+                self.v[k] = T::binop(self.v[k], x);
+                j += lsb(j);
+            }
+            i += lsb(i);
+        }
+    }
+    /// Sum of `a[0..i][0..j]`.
+    pub fn prefix(&self, i: usize, j: usize) -> T {
+        let mut sum = T::ID;
+        let mut i = i;
+        while i > 0 {
+            let mut j = j;
+            while j > 0 {
+                sum = T::binop(self.v[i * (self.m + 1) + j], sum);
+                j -= lsb(j);
+            }
+            i -= lsb(i);
+        }
+        sum
+    }
+    /// Sum of `a[rows][cols]`, require commutativity.
+    pub fn sum(&self, rows: Range<usize>, cols: Range<usize>) -> T
+    where
+        T: Group<M>,
+    {
+        let Range { start: x0, end: x1 } = rows;
+        let Range { start: y0, end: y1 } = cols;
+        let pos = T::binop(self.prefix(x1, y1), self.prefix(x0, y0));
+        let neg = T::binop(self.prefix(x0, y1), self.prefix(x1, y0));
+        T::binop(pos, T::inv(neg))
+    }
+}
+
+/// Offline 2D Fenwick tree, on given points with arbitrary coordinates.
+///
+/// Each node of outer Fenwick(on `x`) holds an inner Fenwick on `y` of points it covers.
+///
+/// # Time complexity
+///
+/// Build *O*(*n* log^2 *n*), *O*(log^2 *n*) per operation, *O*(*n* log *n*) space.
+pub struct Fenwick2dOffline<K, T, M> {
+    xs: OrdIndex<K>,
+    ys: Vec<OrdIndex<K>>,
+    fen: Vec<Fenwick<T, M>>,
+}
+
+impl<K, T, M> Fenwick2dOffline<K, T, M>
+where
+    K: Ord + Copy,
+    T: Copy + Monoid<M>,
+{
+    /// All points `(x, y)` might be added later, duplicates allowed.
+    pub fn new(points: &[(K, K)]) -> Self {
+        let xs = OrdIndex::new(&points.iter().map(|p| p.0).collect::<Vec<_>>());
+        let n = xs.len();
+        let mut raw = vec![vec![]; n + 1];
+        for &(x, y) in points {
+            let mut i = xs.id(x) + 1;
+            while i <= n {
+                raw[i].push(y);
+                i += lsb(i);
+            }
+        }
+        let ys: Vec<_> = raw.iter().map(|r| OrdIndex::new(r)).collect();
+        let fen = ys.iter().map(|y| Fenwick::new(y.len())).collect();
+        Self { xs, ys, fen }
+    }
+    /// Behave as `a[x][y] += w`, `(x, y)` must be given in `new`.
+    pub fn add(&mut self, x: K, y: K, w: T) {
+        let n = self.xs.len();
+        let mut i = self.xs.id(x) + 1;
+        while i <= n {
+            let j = self.ys[i].id(y);
+            self.fen[i].add(j, w);
+            i += lsb(i);
+        }
+    }
+    /// Sum of `a[x][y]` for `x < x1`, `y` in `ys`.
+    fn prefix(&self, x1: &K, ys: &Range<K>) -> T
+    where
+        T: Group<M>,
+    {
+        let mut sum = T::ID;
+        let mut i = self.xs.lower_bound(x1);
+        while i > 0 {
+            let l = self.ys[i].lower_bound(&ys.start);
+            let r = self.ys[i].lower_bound(&ys.end);
+            if l < r {
+                sum = T::binop(self.fen[i].sum(l..r), sum);
+            }
+            i -= lsb(i);
+        }
+        sum
+    }
+    /// Sum of `a[x][y]` in rectangle `xs * ys`, require commutativity.
+    pub fn sum(&self, xs: Range<K>, ys: Range<K>) -> T
+    where
+        T: Group<M>,
+    {
+        if xs.start >= xs.end || ys.start >= ys.end {
+            return T::ID;
+        }
+        T::binop(
+            T::inv(self.prefix(&xs.start, &ys)),
+            self.prefix(&xs.end, &ys),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
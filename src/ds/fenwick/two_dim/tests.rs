@@ -0,0 +1,68 @@
+use super::*;
+use crate::algebra::Add;
+use crate::core::Rng;
+
+#[test]
+fn dense() {
+    let (n, m) = (13, 29);
+    let rng = Rng::new();
+    let mut a = vec![vec![0i64; m]; n];
+    let mut fen = Fenwick2d::<i64, Add>::new(n, m);
+    for _ in 0..1000 {
+        let i = rng.gen() as usize % n;
+        let j = rng.gen() as usize % m;
+        let x = (rng.gen() % 100) as i64 - 50;
+        a[i][j] += x;
+        fen.add(i, j, x);
+
+        let mut x0 = rng.gen() as usize % (n + 1);
+        let mut x1 = rng.gen() as usize % (n + 1);
+        let mut y0 = rng.gen() as usize % (m + 1);
+        let mut y1 = rng.gen() as usize % (m + 1);
+        if x0 > x1 {
+            std::mem::swap(&mut x0, &mut x1);
+        }
+        if y0 > y1 {
+            std::mem::swap(&mut y0, &mut y1);
+        }
+        let res: i64 = a[x0..x1]
+            .iter()
+            .map(|r| r[y0..y1].iter().sum::<i64>())
+            .sum();
+        assert_eq!(fen.sum(x0..x1, y0..y1), res);
+    }
+}
+
+#[test]
+fn offline() {
+    let n = 300;
+    let rng = Rng::new();
+    let coord = |rng: &Rng| (rng.gen() % 2_000_000_000) as i64 - 1_000_000_000;
+    let points: Vec<(i64, i64)> = (0..n).map(|_| (coord(&rng), coord(&rng))).collect();
+    let mut w = vec![0i64; n];
+    let mut fen = Fenwick2dOffline::<i64, i64, Add>::new(&points);
+    for _ in 0..1000 {
+        let k = rng.gen() as usize % n;
+        let x = (rng.gen() % 100) as i64;
+        w[k] += x;
+        fen.add(points[k].0, points[k].1, x);
+
+        let (mut x0, mut x1, mut y0, mut y1) = (coord(&rng), coord(&rng), coord(&rng), coord(&rng));
+        if x0 > x1 {
+            std::mem::swap(&mut x0, &mut x1);
+        }
+        if y0 > y1 {
+            std::mem::swap(&mut y0, &mut y1);
+        }
+        let res: i64 = (0..n)
+            .filter(|&k| {
+                let (x, y) = points[k];
+                x0 <= x && x < x1 && y0 <= y && y < y1
+            })
+            .map(|k| w[k])
+            .sum();
+        assert_eq!(fen.sum(x0..x1, y0..y1), res);
+    }
+    let all: i64 = w.iter().sum();
+    assert_eq!(fen.sum(i64::MIN..i64::MAX, i64::MIN..i64::MAX), all);
+}
@@ -57,6 +57,12 @@ impl<T: Clone> ImVec<T> {
             .expect("Capacity not enough!");
         entry.take()
     }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
     pub fn get(&self, i: usize) -> Option<&T> {
         self.root.get(i, self.level)
@@ -1,13 +1,19 @@
 //! Data structures.
 
 mod bit_vec;
+mod disjoint_sparse_table;
 pub mod dsu;
 pub mod fenwick;
 mod im_vec;
+mod radix_heap;
+mod rmq;
 pub mod seg;
 mod sparse_table;
 pub mod treap;
 
 pub use self::bit_vec::BitVec;
+pub use self::disjoint_sparse_table::DisjointSparseTable;
 pub use self::im_vec::ImVec;
+pub use self::radix_heap::RadixHeap;
+pub use self::rmq::{LinearRmq, RangeQuery};
 pub use self::sparse_table::SparseTable;
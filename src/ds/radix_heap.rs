@@ -0,0 +1,62 @@
+/// Monotone min-heap on `u64` keys, i.e. pushed key must `>=` last popped key.
+///
+/// # Time complexity
+///
+/// Amortized *O*(log *C*) per operation, `C` for max key.
+#[derive(Clone, Debug)]
+pub struct RadixHeap<V> {
+    v: Vec<Vec<(u64, V)>>,
+    last: u64,
+    len: usize,
+}
+
+#[inline]
+fn bucket(x: u64, last: u64) -> usize {
+    64 - (x ^ last).leading_zeros() as usize
+}
+
+impl<V> Default for RadixHeap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> RadixHeap<V> {
+    pub fn new() -> Self {
+        Self {
+            v: (0..65).map(|_| vec![]).collect(),
+            last: 0,
+            len: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn push(&mut self, key: u64, val: V) {
+        debug_assert!(key >= self.last);
+        self.len += 1;
+        self.v[bucket(key, self.last)].push((key, val));
+    }
+    pub fn pop(&mut self) -> Option<(u64, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.v[0].is_empty() {
+            // redistribute first non-empty bucket by its min key
+            let i = (1..65).find(|&i| !self.v[i].is_empty()).unwrap();
+            let b = std::mem::take(&mut self.v[i]);
+            self.last = b.iter().map(|e| e.0).min().unwrap();
+            for (key, val) in b {
+                self.v[bucket(key, self.last)].push((key, val));
+            }
+        }
+        self.len -= 1;
+        self.v[0].pop()
+    }
+}
+
+#[cfg(test)]
+mod tests;
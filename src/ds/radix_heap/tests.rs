@@ -0,0 +1,26 @@
+use super::*;
+use crate::core::Rng;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+#[test]
+fn vs_binary_heap() {
+    let rng = Rng::new();
+    let mut h = RadixHeap::new();
+    let mut b = BinaryHeap::new();
+    let mut last = 0;
+    for i in 0..10000 {
+        if rng.gen() % 3 != 0 {
+            let key = last + (rng.gen() % (1 << (rng.gen() % 40)));
+            h.push(key, i);
+            b.push(Reverse(key));
+        } else {
+            let x = h.pop().map(|e| e.0);
+            assert_eq!(x, b.pop().map(|e| e.0));
+            if let Some(x) = x {
+                last = x;
+            }
+        }
+        assert_eq!(h.len(), b.len());
+    }
+}
@@ -0,0 +1,111 @@
+use super::{DisjointSparseTable, SparseTable};
+use crate::algebra::Monoid;
+use std::ops::Range;
+
+/// Static range query structure, built once from a slice.
+pub trait RangeQuery<T> {
+    fn build(a: &[T]) -> Self;
+    fn query(&self, r: Range<usize>) -> T;
+}
+
+impl<T: Monoid<M> + Copy, M> RangeQuery<T> for SparseTable<T, M> {
+    fn build(a: &[T]) -> Self {
+        Self::new(a)
+    }
+    fn query(&self, r: Range<usize>) -> T {
+        self.query(r)
+    }
+}
+impl<T: Monoid<M> + Copy, M> RangeQuery<T> for DisjointSparseTable<T, M> {
+    fn build(a: &[T]) -> Self {
+        Self::new(a)
+    }
+    fn query(&self, r: Range<usize>) -> T {
+        self.query(r)
+    }
+}
+
+const B: usize = 64;
+
+/// For static RMQ task, in linear space.
+///
+/// Split into blocks of 64, sparse table over block sums, and in-block answer by monotone stack
+/// kept as bitmask, `mask[i]` bits are the stack after pushing `a[i]`.
+///
+/// Warning: must be *Selective* monoid, i.e. `binop(x, y)` is `x` or `y`, e.g. `Min`, `Max`.
+///
+/// # Time complexity
+///
+/// Build *O*(*n*), query *O*(1).
+#[derive(Clone, Debug)]
+pub struct LinearRmq<T, M> {
+    a: Vec<T>,
+    mask: Vec<u64>,
+    st: SparseTable<T, M>,
+}
+
+impl<T, M> LinearRmq<T, M>
+where
+    T: Monoid<M> + Copy + PartialEq,
+{
+    pub fn new(a: &[T]) -> Self {
+        let mut mask = vec![0; a.len()];
+        for (blk, ms) in a.chunks(B).zip(mask.chunks_mut(B)) {
+            let mut cur = 0_u64;
+            for (i, &x) in blk.iter().enumerate() {
+                // pop tops dominated by x
+                while cur != 0 {
+                    let top = 63 - cur.leading_zeros() as usize;
+                    if T::binop(x, blk[top]) == x {
+                        cur ^= 1 << top;
+                    } else {
+                        break;
+                    }
+                }
+                cur |= 1 << i;
+                ms[i] = cur;
+            }
+        }
+        let sums: Vec<_> = a
+            .chunks(B)
+            .map(|blk| blk.iter().fold(T::ID, |s, &x| T::binop(s, x)))
+            .collect();
+        Self {
+            a: Vec::from(a),
+            mask,
+            st: SparseTable::new(&sums),
+        }
+    }
+    pub fn query(&self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        debug_assert!(l < r);
+        let r = r - 1;
+        let (bl, br) = (l / B, r / B);
+        if bl == br {
+            return self.in_block(l, r);
+        }
+        let mut res = T::binop(self.in_block(l, bl * B + B - 1), self.in_block(br * B, r));
+        if bl + 1 < br {
+            res = T::binop(res, self.st.query(bl + 1..br));
+        }
+        res
+    }
+    /// Within one block, `[l, r]` inclusive.
+    #[inline]
+    fn in_block(&self, l: usize, r: usize) -> T {
+        let m = self.mask[r] & (!0 << (l % B));
+        self.a[r / B * B + m.trailing_zeros() as usize]
+    }
+}
+
+impl<T: Monoid<M> + Copy + PartialEq, M> RangeQuery<T> for LinearRmq<T, M> {
+    fn build(a: &[T]) -> Self {
+        Self::new(a)
+    }
+    fn query(&self, r: Range<usize>) -> T {
+        self.query(r)
+    }
+}
+
+#[cfg(test)]
+mod tests;
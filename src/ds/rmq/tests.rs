@@ -0,0 +1,43 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+use crate::string::SuffixArray;
+
+#[test]
+fn min_max() {
+    let rng = Rng::new();
+    for &n in &[1, 5, 63, 64, 65, 130, 300] {
+        for &k in &[2, 1000] {
+            let a: Vec<i64> = (0..n).map(|_| (rng.gen() % k) as i64).collect();
+            let mn = LinearRmq::<_, Min>::new(&a);
+            let mx = LinearRmq::<_, Max>::new(&a);
+            for l in 0..n {
+                for r in l + 1..=n {
+                    assert_eq!(mn.query(l..r), *a[l..r].iter().min().unwrap());
+                    assert_eq!(mx.query(l..r), *a[l..r].iter().max().unwrap());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn suffix_array_lcp() {
+    let rng = Rng::new();
+    let s: Vec<u8> = (0..500).map(|_| b'a' + (rng.gen() % 3) as u8).collect();
+    let sa = SuffixArray::new(&s);
+    let sb = SuffixArray::<LinearRmq<usize, Min>>::build(&s);
+    for _ in 0..2000 {
+        let i = rng.gen() as usize % s.len();
+        let j = rng.gen() as usize % s.len();
+        if i != j {
+            let h = s[i..]
+                .iter()
+                .zip(&s[j..])
+                .take_while(|(x, y)| x == y)
+                .count();
+            assert_eq!(sa.lcp(i, j), h);
+            assert_eq!(sb.lcp(i, j), h);
+        }
+    }
+}
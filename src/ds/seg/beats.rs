@@ -0,0 +1,288 @@
+use crate::algebra::{Max, Min, Monoid};
+use crate::core::num::Integer;
+use std::cmp::{max, min, Ordering};
+use std::convert::TryFrom;
+use std::ops::Range;
+
+/// `x * n`, the count `n` converted into `T`.
+#[inline]
+fn times<T: Integer + TryFrom<usize>>(x: T, n: usize) -> T {
+    x * T::try_from(n).ok().expect("count overflows T")
+}
+
+/// Combine two optional candidates, `None` means no candidate.
+#[inline]
+fn join<T>(a: Option<T>, b: Option<T>, f: fn(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Summary of a segment, top two distinct max/min with count of top.
+///
+/// `max2`/`min2` are `None` if all values equal, `max`/`min` meaningless if `len == 0`.
+#[derive(Clone, Copy, Debug)]
+struct Node<T> {
+    sum: T,
+    len: usize,
+    max: T,
+    max2: Option<T>,
+    max_c: usize,
+    min: T,
+    min2: Option<T>,
+    min_c: usize,
+}
+
+impl<T> Node<T>
+where
+    T: Integer + Copy + TryFrom<usize> + Monoid<Max> + Monoid<Min>,
+{
+    fn empty() -> Self {
+        Self {
+            sum: T::ZERO,
+            len: 0,
+            max: <T as Monoid<Max>>::ID,
+            max2: None,
+            max_c: 0,
+            min: <T as Monoid<Min>>::ID,
+            min2: None,
+            min_c: 0,
+        }
+    }
+    fn leaf(x: T) -> Self {
+        Self {
+            sum: x,
+            len: 1,
+            max: x,
+            max2: None,
+            max_c: 1,
+            min: x,
+            min2: None,
+            min_c: 1,
+        }
+    }
+    fn merge(l: Self, r: Self) -> Self {
+        if l.len == 0 {
+            return r;
+        }
+        if r.len == 0 {
+            return l;
+        }
+        let (max2, max_c) = match l.max.cmp(&r.max) {
+            Ordering::Greater => (join(l.max2, Some(r.max), max), l.max_c),
+            Ordering::Less => (join(Some(l.max), r.max2, max), r.max_c),
+            Ordering::Equal => (join(l.max2, r.max2, max), l.max_c + r.max_c),
+        };
+        let (min2, min_c) = match l.min.cmp(&r.min) {
+            Ordering::Less => (join(l.min2, Some(r.min), min), l.min_c),
+            Ordering::Greater => (join(Some(l.min), r.min2, min), r.min_c),
+            Ordering::Equal => (join(l.min2, r.min2, min), l.min_c + r.min_c),
+        };
+        Self {
+            sum: l.sum + r.sum,
+            len: l.len + r.len,
+            max: max(l.max, r.max),
+            max2,
+            max_c,
+            min: min(l.min, r.min),
+            min2,
+            min_c,
+        }
+    }
+}
+
+/// Segment tree beats, Ji Ruyi's driver tree.
+///
+/// Support range chmin/chmax/add, and query range sum/max/min.
+/// `T` is one of `i32`, `i64`, `usize`, the integers with `Monoid<Max>` and `Monoid<Min>`.
+/// Their identities are only returned for empty ranges, values may be anything in `T`.
+///
+/// # Time complexity
+///
+/// Amortized *O*(log^2 *n*) per operation, *O*(log *n*) if without `add`.
+#[derive(Clone, Debug)]
+pub struct SegBeats<T> {
+    N: usize,
+    a: Vec<Node<T>>,
+    d: Vec<T>,
+}
+
+impl<T> SegBeats<T>
+where
+    T: Integer + Copy + TryFrom<usize> + Monoid<Max> + Monoid<Min>,
+{
+    /// View of `a[0..n]`, filled with `0`.
+    pub fn new(n: usize) -> Self {
+        Self::from(&vec![T::ZERO; n])
+    }
+    pub fn from(leaf: &[T]) -> Self {
+        let N = leaf.len().next_power_of_two();
+        let mut a = vec![Node::empty(); N << 1];
+        for (i, &x) in leaf.iter().enumerate() {
+            a[N + i] = Node::leaf(x);
+        }
+        for i in (1..N).rev() {
+            a[i] = Node::merge(a[i << 1], a[i << 1 | 1]);
+        }
+        Self {
+            N,
+            a,
+            d: vec![T::ZERO; N],
+        }
+    }
+    /// `a[i] = min(a[i], x)` for `i` in range.
+    pub fn chmin(&mut self, r: Range<usize>, x: T) {
+        let Range { start: l, end: r } = r;
+        self.__chmin(l, r, x, 1, 0, self.N);
+    }
+    /// `a[i] = max(a[i], x)` for `i` in range.
+    pub fn chmax(&mut self, r: Range<usize>, x: T) {
+        let Range { start: l, end: r } = r;
+        self.__chmax(l, r, x, 1, 0, self.N);
+    }
+    /// `a[i] += x` for `i` in range.
+    pub fn add(&mut self, r: Range<usize>, x: T) {
+        let Range { start: l, end: r } = r;
+        self.__add(l, r, x, 1, 0, self.N);
+    }
+    pub fn sum(&mut self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(l, r, 1, 0, self.N).sum
+    }
+    /// `Monoid<Max>::ID` if empty.
+    pub fn max(&mut self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(l, r, 1, 0, self.N).max
+    }
+    /// `Monoid<Min>::ID` if empty.
+    pub fn min(&mut self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(l, r, 1, 0, self.N).min
+    }
+    /// = `sum(p..p+1)`
+    pub fn get(&mut self, p: usize) -> T {
+        self.sum(p..p + 1)
+    }
+
+    fn __chmin(&mut self, l: usize, r: usize, x: T, i: usize, sl: usize, sr: usize) {
+        if r <= sl || sr <= l || self.a[i].len == 0 || self.a[i].max <= x {
+            return;
+        }
+        if l <= sl && sr <= r && self.a[i].max2 < Some(x) {
+            return self.apply_chmin(i, x);
+        }
+        let sm = (sl + sr) >> 1;
+        self.push(i);
+        self.__chmin(l, r, x, i << 1, sl, sm);
+        self.__chmin(l, r, x, i << 1 | 1, sm, sr);
+        self.pull(i);
+    }
+    fn __chmax(&mut self, l: usize, r: usize, x: T, i: usize, sl: usize, sr: usize) {
+        if r <= sl || sr <= l || self.a[i].len == 0 || self.a[i].min >= x {
+            return;
+        }
+        if l <= sl && sr <= r && !matches!(self.a[i].min2, Some(y) if y <= x) {
+            return self.apply_chmax(i, x);
+        }
+        let sm = (sl + sr) >> 1;
+        self.push(i);
+        self.__chmax(l, r, x, i << 1, sl, sm);
+        self.__chmax(l, r, x, i << 1 | 1, sm, sr);
+        self.pull(i);
+    }
+    fn __add(&mut self, l: usize, r: usize, x: T, i: usize, sl: usize, sr: usize) {
+        if r <= sl || sr <= l {
+            return;
+        }
+        if l <= sl && sr <= r {
+            return self.apply_add(i, x);
+        }
+        let sm = (sl + sr) >> 1;
+        self.push(i);
+        self.__add(l, r, x, i << 1, sl, sm);
+        self.__add(l, r, x, i << 1 | 1, sm, sr);
+        self.pull(i);
+    }
+    fn __query(&mut self, l: usize, r: usize, i: usize, sl: usize, sr: usize) -> Node<T> {
+        if r <= sl || sr <= l {
+            return Node::empty();
+        }
+        if l <= sl && sr <= r {
+            return self.a[i];
+        }
+        self.push(i);
+        let sm = (sl + sr) >> 1;
+        let x = self.__query(l, r, i << 1, sl, sm);
+        let y = self.__query(l, r, i << 1 | 1, sm, sr);
+        Node::merge(x, y)
+    }
+    /// Push down add tag, then chmin/chmax implied by parent's max/min.
+    #[inline]
+    fn push(&mut self, i: usize) {
+        if self.d[i] != T::ZERO {
+            self.apply_add(i << 1, self.d[i]);
+            self.apply_add(i << 1 | 1, self.d[i]);
+            self.d[i] = T::ZERO;
+        }
+        let [max, min] = [self.a[i].max, self.a[i].min];
+        for j in [i << 1, i << 1 | 1].iter().copied() {
+            if self.a[j].len == 0 {
+                continue;
+            }
+            if self.a[j].max > max {
+                self.apply_chmin(j, max);
+            }
+            if self.a[j].min < min {
+                self.apply_chmax(j, min);
+            }
+        }
+    }
+    #[inline]
+    fn apply_add(&mut self, i: usize, x: T) {
+        let u = &mut self.a[i];
+        if u.len == 0 {
+            return;
+        }
+        u.sum = u.sum + times(x, u.len);
+        u.max = u.max + x;
+        u.max2 = u.max2.map(|y| y + x);
+        u.min = u.min + x;
+        u.min2 = u.min2.map(|y| y + x);
+        if i < self.N {
+            self.d[i] = self.d[i] + x;
+        }
+    }
+    /// Require `max2 < x < max`.
+    #[inline]
+    fn apply_chmin(&mut self, i: usize, x: T) {
+        let u = &mut self.a[i];
+        u.sum = u.sum - times(u.max - x, u.max_c);
+        if u.min == u.max {
+            u.min = x;
+        } else if u.min2 == Some(u.max) {
+            u.min2 = Some(x);
+        }
+        u.max = x;
+    }
+    /// Require `min < x < min2`.
+    #[inline]
+    fn apply_chmax(&mut self, i: usize, x: T) {
+        let u = &mut self.a[i];
+        u.sum = u.sum + times(x - u.min, u.min_c);
+        if u.max == u.min {
+            u.max = x;
+        } else if u.max2 == Some(u.min) {
+            u.max2 = Some(x);
+        }
+        u.min = x;
+    }
+    #[inline]
+    fn pull(&mut self, i: usize) {
+        self.a[i] = Node::merge(self.a[i << 1], self.a[i << 1 | 1]);
+    }
+}
+
+#[cfg(test)]
+mod tests;
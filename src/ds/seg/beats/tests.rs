@@ -0,0 +1,124 @@
+use super::*;
+use crate::core::Rng;
+
+#[test]
+fn chmin_chmax_add() {
+    let rng = Rng::new();
+    for &n in [1, 7, 64, 100].iter() {
+        let mut a: Vec<i64> = (0..n).map(|_| (rng.gen() % 100) as i64 - 50).collect();
+        let mut s = SegBeats::from(&a);
+        for _ in 0..2000 {
+            let mut l = rng.gen() as usize % (n + 1);
+            let mut r = rng.gen() as usize % (n + 1);
+            if l > r {
+                std::mem::swap(&mut l, &mut r);
+            }
+            let x = (rng.gen() % 100) as i64 - 50;
+            match rng.gen() % 4 {
+                0 => {
+                    s.chmin(l..r, x);
+                    a[l..r].iter_mut().for_each(|y| *y = min(*y, x));
+                }
+                1 => {
+                    s.chmax(l..r, x);
+                    a[l..r].iter_mut().for_each(|y| *y = max(*y, x));
+                }
+                2 => {
+                    s.add(l..r, x);
+                    a[l..r].iter_mut().for_each(|y| *y += x);
+                }
+                _ => {
+                    assert_eq!(s.sum(l..r), a[l..r].iter().sum::<i64>());
+                    if l < r {
+                        assert_eq!(s.max(l..r), *a[l..r].iter().max().unwrap());
+                        assert_eq!(s.min(l..r), *a[l..r].iter().min().unwrap());
+                    }
+                }
+            }
+        }
+        for i in 0..n {
+            assert_eq!(s.get(i), a[i]);
+        }
+    }
+}
+
+#[test]
+fn empty_range() {
+    let mut s = SegBeats::<i32>::new(5);
+    s.add(1..4, 3);
+    s.chmin(0..5, 2);
+    assert_eq!(s.sum(0..5), 6);
+    assert_eq!(s.max(2..2), <i32 as Monoid<Max>>::ID);
+    assert_eq!(s.min(2..2), <i32 as Monoid<Min>>::ID);
+}
+
+#[test]
+fn unsigned() {
+    let mut s = SegBeats::<usize>::new(4);
+    s.add(0..4, 5);
+    assert_eq!(s.max(0..4), 5);
+    s.chmin(0..4, 3);
+    assert_eq!(s.sum(0..4), 12);
+    s.chmax(1..3, 4);
+    s.chmin(0..1, 0);
+    assert_eq!((0..4).map(|i| s.get(i)).collect::<Vec<_>>(), [0, 4, 4, 3]);
+    assert_eq!(s.min(0..4), 0);
+    assert_eq!(s.max(0..4), 4);
+}
+
+/// Values sitting on the `Monoid<Max>`/`Monoid<Min>` identities are ordinary values.
+#[test]
+fn identity_values() {
+    let lo = <i32 as Monoid<Max>>::ID;
+    let hi = <i32 as Monoid<Min>>::ID;
+    let rng = Rng::new();
+    // Mixing both ends, or more than two such values, overflows `i32`.
+    let sets = [[lo, lo + 1, -1, 0, 1], [hi, hi - 1, -1, 0, 1]];
+    for (n, v) in (1..=2).flat_map(|n| sets.iter().map(move |v| (n, v))) {
+        let pick = |r: u64| v[r as usize % v.len()];
+        let mut a: Vec<i32> = (0..n).map(|_| pick(rng.gen())).collect();
+        let mut s = SegBeats::from(&a);
+        for _ in 0..2000 {
+            let mut l = rng.gen() as usize % (n + 1);
+            let mut r = rng.gen() as usize % (n + 1);
+            if l > r {
+                std::mem::swap(&mut l, &mut r);
+            }
+            let x = pick(rng.gen());
+            match rng.gen() % 4 {
+                0 => {
+                    s.chmin(l..r, x);
+                    a[l..r].iter_mut().for_each(|y| *y = min(*y, x));
+                }
+                1 => {
+                    s.chmax(l..r, x);
+                    a[l..r].iter_mut().for_each(|y| *y = max(*y, x));
+                }
+                2 => {
+                    s.add(l..r, 1);
+                    s.add(l..r, -1);
+                }
+                _ => {
+                    if l < r {
+                        assert_eq!(s.max(l..r), *a[l..r].iter().max().unwrap());
+                        assert_eq!(s.min(l..r), *a[l..r].iter().min().unwrap());
+                    }
+                }
+            }
+        }
+        for i in 0..n {
+            assert_eq!(s.get(i), a[i]);
+        }
+    }
+}
+
+#[test]
+fn second_max_on_identity() {
+    let lo = <i32 as Monoid<Max>>::ID;
+    let mut s = SegBeats::from(&[0, lo]);
+    s.add(0..2, 5);
+    assert_eq!(s.min(0..2), lo + 5);
+    s.chmin(0..2, lo + 4);
+    assert_eq!(s.max(0..2), lo + 4);
+    assert_eq!(s.sum(0..2), 2 * (lo + 4));
+}
@@ -0,0 +1,259 @@
+use crate::algebra::{Affine, Monoid};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+// node 0 is null, i.e. an untouched subtree.
+const NIL: usize = 0;
+
+/// Dynamic segment tree, nodes created only on touch.
+///
+/// For huge index range `0..n`, e.g. `n = 1e18`, without offline compressing.
+/// Untouched elements are viewed as `ID`.
+///
+/// # Time complexity
+///
+/// *O*(log *n*) per operation, with *O*(log *n*) new nodes per update.
+#[derive(Clone, Debug)]
+pub struct SegDynamic<T, M> {
+    n: usize,
+    a: Vec<T>,
+    c: Vec<[usize; 2]>,
+    _m: PhantomData<M>,
+}
+
+impl<T, M> SegDynamic<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// View of `a[0..n]`, filled with `ID`.
+    pub fn new(n: usize) -> Self {
+        Self::with_capacity(n, 0)
+    }
+    /// Reserve `cap` nodes, usually `q * log n` for `q` updates.
+    pub fn with_capacity(n: usize, cap: usize) -> Self {
+        let mut a = Vec::with_capacity(cap + 2);
+        let mut c = Vec::with_capacity(cap + 2);
+        // null and root
+        a.resize(2, T::ID);
+        c.resize(2, [NIL; 2]);
+        Self {
+            n,
+            a,
+            c,
+            _m: PhantomData,
+        }
+    }
+    /// Behave as `a[i] += x`.
+    pub fn add(&mut self, i: usize, x: T) {
+        debug_assert!(i < self.n);
+        self.__update(1, 0, self.n, i, &mut |y| T::binop(y, x));
+    }
+    pub fn set(&mut self, i: usize, x: T) {
+        debug_assert!(i < self.n);
+        self.__update(1, 0, self.n, i, &mut |_| x);
+    }
+    /// = `sum(i..i+1)`
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i..i + 1)
+    }
+    pub fn sum(&self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(1, 0, self.n, l, r)
+    }
+    /// = `sum(0..n)`
+    pub fn all(&self) -> T {
+        self.a[1]
+    }
+    /// Number of created nodes.
+    pub fn nodes(&self) -> usize {
+        self.a.len() - 1
+    }
+
+    fn __update<G>(&mut self, i: usize, sl: usize, sr: usize, p: usize, g: &mut G)
+    where
+        G: FnMut(T) -> T,
+    {
+        if sr - sl == 1 {
+            self.a[i] = g(self.a[i]);
+            return;
+        }
+        let sm = sl + (sr - sl) / 2;
+        if p < sm {
+            let v = self.child(i, 0);
+            self.__update(v, sl, sm, p, g);
+        } else {
+            let v = self.child(i, 1);
+            self.__update(v, sm, sr, p, g);
+        }
+        self.pull(i);
+    }
+    fn __query(&self, i: usize, sl: usize, sr: usize, l: usize, r: usize) -> T {
+        if i == NIL || r <= sl || sr <= l {
+            return T::ID;
+        }
+        if l <= sl && sr <= r {
+            return self.a[i];
+        }
+        let sm = sl + (sr - sl) / 2;
+        let [il, ir] = self.c[i];
+        let x = self.__query(il, sl, sm, l, r);
+        let y = self.__query(ir, sm, sr, l, r);
+        T::binop(x, y)
+    }
+    #[inline]
+    fn child(&mut self, i: usize, k: usize) -> usize {
+        if self.c[i][k] == NIL {
+            self.c[i][k] = self.a.len();
+            self.a.push(T::ID);
+            self.c.push([NIL; 2]);
+        }
+        self.c[i][k]
+    }
+    #[inline]
+    fn pull(&mut self, i: usize) {
+        let [l, r] = self.c[i];
+        self.a[i] = T::binop(self.a[l], self.a[r]);
+    }
+}
+
+/// Dynamic segment tree with lazy propagation, nodes created only on touch.
+///
+/// `(T,M)` is monoid, `(U,D)` is monoid action, same as `SegLazy`.
+/// Untouched segment of length `len` is viewed as `init(len)`, default `ID`.
+/// So `T` carrying length, e.g. range add range sum, should use `with_init`.
+///
+/// # Time complexity
+///
+/// *O*(log *n*) per operation, with *O*(log *n*) new nodes per operation.
+#[derive(Clone, Debug)]
+pub struct SegDynamicLazy<T, U, M, D, F> {
+    n: usize,
+    a: Vec<T>,
+    d: Vec<U>,
+    c: Vec<[usize; 2]>,
+    init: fn(usize) -> T,
+    _m: PhantomData<(M, D, F)>,
+}
+
+impl<T, U, M, D, F> SegDynamicLazy<T, U, M, D, F>
+where
+    T: Monoid<M> + Copy + Affine<U, F>,
+    U: Monoid<D> + Copy + PartialEq + Eq,
+{
+    /// View of `a[0..n]`, filled with `ID`.
+    pub fn new(n: usize) -> Self {
+        Self::with_init(n, |_| T::ID)
+    }
+    /// View of `a[0..n]`, where any untouched `a[l..r]` sum to `init(r-l)`.
+    pub fn with_init(n: usize, init: fn(usize) -> T) -> Self {
+        Self {
+            n,
+            a: vec![T::ID, init(n)],
+            d: vec![U::ID; 2],
+            c: vec![[NIL; 2]; 2],
+            init,
+            _m: PhantomData,
+        }
+    }
+    pub fn add(&mut self, r: Range<usize>, u: U) {
+        let Range { start: l, end: r } = r;
+        self.__update(1, 0, self.n, l, r, u);
+    }
+    pub fn sum(&mut self, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(1, 0, self.n, l, r)
+    }
+    /// = `sum(p..p+1)`
+    pub fn get(&mut self, p: usize) -> T {
+        self.sum(p..p + 1)
+    }
+    /// = `sum(0..n)`
+    pub fn all(&self) -> T {
+        self.a[1]
+    }
+    pub fn set(&mut self, p: usize, x: T) {
+        debug_assert!(p < self.n);
+        self.__set(1, 0, self.n, p, x);
+    }
+    /// Number of created nodes.
+    pub fn nodes(&self) -> usize {
+        self.a.len() - 1
+    }
+
+    fn __update(&mut self, i: usize, sl: usize, sr: usize, l: usize, r: usize, u: U) {
+        if r <= sl || sr <= l {
+            return;
+        }
+        if l <= sl && sr <= r {
+            return self.apply(i, u);
+        }
+        let sm = sl + (sr - sl) / 2;
+        self.push(i, sl, sm, sr);
+        let [il, ir] = self.c[i];
+        self.__update(il, sl, sm, l, r, u);
+        self.__update(ir, sm, sr, l, r, u);
+        self.pull(i);
+    }
+    fn __query(&mut self, i: usize, sl: usize, sr: usize, l: usize, r: usize) -> T {
+        if r <= sl || sr <= l {
+            return T::ID;
+        }
+        if l <= sl && sr <= r {
+            return self.a[i];
+        }
+        let sm = sl + (sr - sl) / 2;
+        self.push(i, sl, sm, sr);
+        let [il, ir] = self.c[i];
+        let x = self.__query(il, sl, sm, l, r);
+        let y = self.__query(ir, sm, sr, l, r);
+        T::binop(x, y)
+    }
+    fn __set(&mut self, i: usize, sl: usize, sr: usize, p: usize, x: T) {
+        if sr - sl == 1 {
+            self.a[i] = x;
+            return;
+        }
+        let sm = sl + (sr - sl) / 2;
+        self.push(i, sl, sm, sr);
+        let [il, ir] = self.c[i];
+        if p < sm {
+            self.__set(il, sl, sm, p, x);
+        } else {
+            self.__set(ir, sm, sr, p, x);
+        }
+        self.pull(i);
+    }
+    /// Create children if untouched, then push down lazy tag.
+    #[inline]
+    fn push(&mut self, i: usize, sl: usize, sm: usize, sr: usize) {
+        if self.c[i][0] == NIL {
+            self.c[i] = [self.alloc(sm - sl), self.alloc(sr - sm)];
+        }
+        if self.d[i] != U::ID {
+            let [il, ir] = self.c[i];
+            self.apply(il, self.d[i]);
+            self.apply(ir, self.d[i]);
+            self.d[i] = U::ID;
+        }
+    }
+    #[inline]
+    fn apply(&mut self, i: usize, u: U) {
+        self.a[i] = T::affine(self.a[i], u);
+        self.d[i] = U::binop(self.d[i], u);
+    }
+    #[inline]
+    fn pull(&mut self, i: usize) {
+        let [l, r] = self.c[i];
+        self.a[i] = T::binop(self.a[l], self.a[r]);
+    }
+    #[inline]
+    fn alloc(&mut self, len: usize) -> usize {
+        self.a.push((self.init)(len));
+        self.d.push(U::ID);
+        self.c.push([NIL; 2]);
+        self.a.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
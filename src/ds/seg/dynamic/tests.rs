@@ -0,0 +1,114 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+use std::collections::BTreeMap;
+
+#[test]
+fn sum_small() {
+    let n = 100;
+    let rng = Rng::new();
+    let mut a = vec![0i64; n];
+    let mut s = SegDynamic::<i64, Add>::new(n);
+    for _ in 0..1000 {
+        let i = rng.gen() as usize % n;
+        let x = (rng.gen() % 1000) as i64 - 500;
+        match rng.gen() % 3 {
+            0 => {
+                a[i] += x;
+                s.add(i, x);
+            }
+            1 => {
+                a[i] = x;
+                s.set(i, x);
+            }
+            _ => {
+                let mut l = rng.gen() as usize % (n + 1);
+                let mut r = rng.gen() as usize % (n + 1);
+                if l > r {
+                    std::mem::swap(&mut l, &mut r);
+                }
+                assert_eq!(s.sum(l..r), a[l..r].iter().sum::<i64>());
+            }
+        }
+        assert_eq!(s.get(i), a[i]);
+    }
+    assert_eq!(s.all(), a.iter().sum::<i64>());
+}
+
+#[test]
+fn max_huge() {
+    let n = 1_000_000_000_000_000_000;
+    let rng = Rng::new();
+    let mut a = BTreeMap::new();
+    let mut s = SegDynamic::<i64, Max>::new(n);
+    for _ in 0..1000 {
+        let i = rng.gen() as usize % n;
+        let x = (rng.gen() % 1000) as i64;
+        a.insert(i, x);
+        s.set(i, x);
+        let mut l = rng.gen() as usize % n;
+        let mut r = rng.gen() as usize % n;
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        let res = a
+            .range(l..r)
+            .map(|(_, &x)| x)
+            .max()
+            .unwrap_or(<i64 as Monoid<Max>>::ID);
+        assert_eq!(s.sum(l..r), res);
+    }
+    assert!(s.nodes() <= 1000 * 61);
+}
+
+#[test]
+fn add_sum_lazy() {
+    monoid_new!(impl S for (i64, i64), (0, 0), |x, y| (x.0 + y.0, x.1 + y.1));
+    affine_new!(impl F<i64> for (i64, i64), |x, u| (x.0 + u * x.1, x.1));
+    let n = 100;
+    let rng = Rng::new();
+    let mut a = vec![0i64; n];
+    let mut s = SegDynamicLazy::<_, i64, S, Add, F>::with_init(n, |len| (0, len as i64));
+    for _ in 0..1000 {
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        match rng.gen() % 3 {
+            0 => {
+                let x = (rng.gen() % 100) as i64 - 50;
+                s.add(l..r, x);
+                for i in l..r {
+                    a[i] += x;
+                }
+            }
+            1 if l < n => {
+                let x = (rng.gen() % 100) as i64;
+                s.set(l, (x, 1));
+                a[l] = x;
+            }
+            _ => {
+                let res = s.sum(l..r);
+                assert_eq!(res, (a[l..r].iter().sum::<i64>(), (r - l) as i64));
+            }
+        }
+    }
+    assert_eq!(s.all().0, a.iter().sum::<i64>());
+}
+
+#[test]
+fn min_add_huge_lazy() {
+    affine_new!(impl F<i64> for i64, |x, u| x + u);
+    let n = 1 << 40;
+    let mut s = SegDynamicLazy::<i64, i64, Min, Add, F>::with_init(n, |_| 0);
+    s.add(10..1 << 30, 5);
+    s.add(1 << 20..1 << 35, -3);
+    assert_eq!(s.sum(0..10), 0);
+    assert_eq!(s.sum(10..1 << 20), 5);
+    assert_eq!(s.sum(1 << 30..n), -3);
+    assert_eq!(s.sum(1 << 20..1 << 30), 2);
+    assert_eq!(s.all(), -3);
+    assert_eq!(s.get((1 << 35) - 1), -3);
+    assert_eq!(s.get(1 << 35), 0);
+}
@@ -7,6 +7,7 @@ use std::ops::Range;
 /// `(T,M)` is monoid, `(U,D)` is monoid action, or endomorphism, defined by `F(T,U)->T`.
 /// `F` usually is affine. Technically should hold property `F(T*T) = F(T)*F(T)`
 pub struct SegLazy<T, U, M, D, F> {
+    n: usize,
     N: usize,
     L: usize,
     a: Vec<T>,
@@ -21,6 +22,7 @@ where
     pub fn new(n: usize) -> Self {
         let (N, L, a, d) = Self::alloc(n);
         Self {
+            n,
             N,
             L,
             a,
@@ -35,6 +37,7 @@ where
             a[i] = T::binop(a[i << 1], a[i << 1 | 1]);
         }
         Self {
+            n: leaf.len(),
             N,
             L,
             a,
@@ -77,6 +80,96 @@ where
             self.pull(i);
         }
     }
+    /// Max `r` s.t. `pred(sum(l..r))` holds.
+    ///
+    /// Require `pred(ID)` and `pred` monotone, i.e. once false, false for longer range.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*).
+    pub fn max_right<P>(&mut self, l: usize, mut pred: P) -> usize
+    where
+        P: FnMut(T) -> bool,
+    {
+        debug_assert!(l <= self.n && pred(T::ID));
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.N;
+        for k in (1..=self.L).rev() {
+            self.push(l >> k);
+        }
+        let mut sum = T::ID;
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let x = T::binop(sum, self.a[l]);
+            if !pred(x) {
+                while l < self.N {
+                    self.push(l);
+                    l <<= 1;
+                    let x = T::binop(sum, self.a[l]);
+                    if pred(x) {
+                        sum = x;
+                        l += 1;
+                    }
+                }
+                return l - self.N;
+            }
+            sum = x;
+            l += 1;
+            if l.is_power_of_two() {
+                break;
+            }
+        }
+        self.n
+    }
+    /// Min `l` s.t. `pred(sum(l..r))` holds.
+    ///
+    /// Require `pred(ID)` and `pred` monotone, i.e. once false, false for longer range.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*).
+    pub fn min_left<P>(&mut self, r: usize, mut pred: P) -> usize
+    where
+        P: FnMut(T) -> bool,
+    {
+        debug_assert!(r <= self.n && pred(T::ID));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.N;
+        for k in (1..=self.L).rev() {
+            self.push((r - 1) >> k);
+        }
+        let mut sum = T::ID;
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let x = T::binop(self.a[r], sum);
+            if !pred(x) {
+                while r < self.N {
+                    self.push(r);
+                    r = r << 1 | 1;
+                    let x = T::binop(self.a[r], sum);
+                    if pred(x) {
+                        sum = x;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.N;
+            }
+            sum = x;
+            if r.is_power_of_two() {
+                break;
+            }
+        }
+        0
+    }
 
     fn __update(&mut self, l: usize, r: usize, u: U, i: usize, sl: usize, sr: usize) {
         if r <= sl || sr <= l {
@@ -52,3 +52,38 @@ fn max_add_i32() {
         }
     }
 }
+
+#[test]
+fn max_right_min_left() {
+    let n = 100;
+    let mut a = vec![0; n];
+    affine_new!(impl F<i32> for i32, |x,y| x+y);
+    let mut s = SegLazy::<_, _, Max, Add, F>::from(&a);
+    let rng = Rng::new();
+    for _ in 0..1000 {
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        if rng.gen() & 1 == 0 {
+            let x = rng.gen() as i32 % 55;
+            s.add(l..r, x);
+            for i in l..r {
+                a[i] += x;
+            }
+        } else {
+            let k = rng.gen() as i32 % 300;
+            let mut res = l;
+            while res < n && a[res] < k {
+                res += 1;
+            }
+            assert_eq!(s.max_right(l, |x| x < k), res);
+            let mut res = r;
+            while res > 0 && a[res - 1] < k {
+                res -= 1;
+            }
+            assert_eq!(s.min_left(r, |x| x < k), res);
+        }
+    }
+}
@@ -1,6 +1,12 @@
 //! Segment tree family.
+mod beats;
+mod dynamic;
 mod lazy;
+mod persistent;
 mod tree;
 
+pub use self::beats::SegBeats;
+pub use self::dynamic::{SegDynamic, SegDynamicLazy};
 pub use self::lazy::SegLazy;
+pub use self::persistent::SegPersistent;
 pub use self::tree::SegTree;
@@ -0,0 +1,157 @@
+use crate::algebra::{Group, Monoid};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+// node 0 is null, children point to itself, shared by every untouched subtree.
+const NIL: usize = 0;
+
+/// Persistent segment tree, by path copying.
+///
+/// Each update creates a new version, `versions[v]` is root of version `v`, initial is version `0`.
+///
+/// # Time complexity
+///
+/// *O*(log *n*) per operation, with *O*(log *n*) new nodes per update.
+#[derive(Clone, Debug)]
+pub struct SegPersistent<T, M> {
+    n: usize,
+    a: Vec<T>,
+    c: Vec<[usize; 2]>,
+    pub versions: Vec<usize>,
+    _m: PhantomData<M>,
+}
+
+impl<T, M> SegPersistent<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// Version `0` as `a[0..n]`, filled with `ID`.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            a: vec![T::ID],
+            c: vec![[NIL; 2]],
+            versions: vec![NIL],
+            _m: PhantomData,
+        }
+    }
+    /// Version `0` as `leaf`.
+    pub fn from(leaf: &[T]) -> Self {
+        let mut s = Self::new(leaf.len());
+        let root = s.build(leaf);
+        s.versions[0] = root;
+        s
+    }
+    /// Behave as `a[i] = g(a[i])` on version `ver`, return new version.
+    pub fn update<G>(&mut self, ver: usize, i: usize, g: G) -> usize
+    where
+        G: FnOnce(T) -> T,
+    {
+        debug_assert!(i < self.n);
+        let root = self.__update(self.versions[ver], 0, self.n, i, g);
+        self.versions.push(root);
+        self.versions.len() - 1
+    }
+    /// Behave as `a[i] += x` on version `ver`, return new version.
+    pub fn add(&mut self, ver: usize, i: usize, x: T) -> usize {
+        self.update(ver, i, |y| T::binop(y, x))
+    }
+    /// Behave as `a[i] = x` on version `ver`, return new version.
+    pub fn set(&mut self, ver: usize, i: usize, x: T) -> usize {
+        self.update(ver, i, |_| x)
+    }
+    /// = `sum(ver, i..i+1)`
+    pub fn get(&self, ver: usize, i: usize) -> T {
+        self.sum(ver, i..i + 1)
+    }
+    /// Sum of `a[l..r]` of version `ver`.
+    pub fn sum(&self, ver: usize, r: Range<usize>) -> T {
+        let Range { start: l, end: r } = r;
+        self.__query(self.versions[ver], 0, self.n, l, r)
+    }
+    /// Order statistic on difference `ver_r - ver_l`, e.g. count of values in `a[l..r]`.
+    ///
+    /// Let `count(i..j) = sum(ver_r, i..j) - sum(ver_l, i..j)`. Return the largest `res` with
+    /// `count(0..res) <= k`, i.e. the `k`-th (0-based) value; `n` if `k >= count(0..n)`.
+    ///
+    /// Warning: correct only when difference are all nonnegative.
+    pub fn kth(&self, ver_l: usize, ver_r: usize, k: T) -> usize
+    where
+        T: Group<M> + PartialOrd,
+    {
+        let (mut x, mut y) = (self.versions[ver_l], self.versions[ver_r]);
+        let (mut sl, mut sr) = (0, self.n);
+        let mut k = k;
+        let diff = |x: usize, y: usize| T::binop(T::inv(self.a[x]), self.a[y]);
+        if diff(x, y) <= k {
+            return self.n;
+        }
+        while sr - sl > 1 {
+            let sm = sl + (sr - sl) / 2;
+            let [xl, xr] = self.c[x];
+            let [yl, yr] = self.c[y];
+            let s = diff(xl, yl);
+            if k < s {
+                x = xl;
+                y = yl;
+                sr = sm;
+            } else {
+                k = T::binop(T::inv(s), k);
+                x = xr;
+                y = yr;
+                sl = sm;
+            }
+        }
+        sl
+    }
+
+    fn build(&mut self, leaf: &[T]) -> usize {
+        match leaf.len() {
+            0 => NIL,
+            1 => self.alloc(leaf[0], [NIL; 2]),
+            len => {
+                let l = self.build(&leaf[..len / 2]);
+                let r = self.build(&leaf[len / 2..]);
+                self.alloc(T::binop(self.a[l], self.a[r]), [l, r])
+            }
+        }
+    }
+    fn __update<G>(&mut self, i: usize, sl: usize, sr: usize, p: usize, g: G) -> usize
+    where
+        G: FnOnce(T) -> T,
+    {
+        if sr - sl == 1 {
+            return self.alloc(g(self.a[i]), [NIL; 2]);
+        }
+        let sm = sl + (sr - sl) / 2;
+        let [mut l, mut r] = self.c[i];
+        if p < sm {
+            l = self.__update(l, sl, sm, p, g);
+        } else {
+            r = self.__update(r, sm, sr, p, g);
+        }
+        self.alloc(T::binop(self.a[l], self.a[r]), [l, r])
+    }
+    fn __query(&self, i: usize, sl: usize, sr: usize, l: usize, r: usize) -> T {
+        if i == NIL || r <= sl || sr <= l {
+            return T::ID;
+        }
+        if l <= sl && sr <= r {
+            return self.a[i];
+        }
+        let sm = sl + (sr - sl) / 2;
+        let [il, ir] = self.c[i];
+        let x = self.__query(il, sl, sm, l, r);
+        let y = self.__query(ir, sm, sr, l, r);
+        T::binop(x, y)
+    }
+    #[inline]
+    fn alloc(&mut self, x: T, c: [usize; 2]) -> usize {
+        self.a.push(x);
+        self.c.push(c);
+        self.a.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
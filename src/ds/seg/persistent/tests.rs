@@ -0,0 +1,61 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+
+#[test]
+fn versions() {
+    let n = 50;
+    let rng = Rng::new();
+    let mut a = vec![(0..n).map(|i| i as i64).collect::<Vec<_>>()];
+    let mut s = SegPersistent::<i64, Add>::from(&a[0]);
+    for _ in 0..500 {
+        let v = rng.gen() as usize % a.len();
+        let i = rng.gen() as usize % n;
+        let x = (rng.gen() % 100) as i64;
+        let mut b = a[v].clone();
+        let ver = if rng.gen() & 1 == 0 {
+            b[i] += x;
+            s.add(v, i, x)
+        } else {
+            b[i] = x;
+            s.set(v, i, x)
+        };
+        a.push(b);
+        assert_eq!(ver, a.len() - 1);
+
+        let v = rng.gen() as usize % a.len();
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        assert_eq!(s.sum(v, l..r), a[v][l..r].iter().sum::<i64>());
+    }
+    for (v, b) in a.iter().enumerate() {
+        for i in 0..n {
+            assert_eq!(s.get(v, i), b[i]);
+        }
+    }
+}
+
+// k-th smallest of a[l..r], i.e. static range kth.
+#[test]
+fn range_kth() {
+    let n = 200;
+    let m = 30;
+    let rng = Rng::new();
+    let a: Vec<usize> = (0..n).map(|_| rng.gen() as usize % m).collect();
+    let mut s = SegPersistent::<i32, Add>::new(m);
+    for i in 0..n {
+        s.add(i, a[i], 1);
+    }
+    for l in 0..n {
+        for r in l + 1..=n {
+            let mut b = a[l..r].to_vec();
+            b.sort();
+            let k = rng.gen() as usize % (r - l);
+            assert_eq!(s.kth(l, r, k as i32), b[k]);
+            assert_eq!(s.kth(l, r, (r - l) as i32), m);
+        }
+    }
+}
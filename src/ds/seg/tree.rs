@@ -5,6 +5,7 @@ use std::ops::Range;
 /// Segment tree(iterative implementation).
 #[derive(Clone, Debug)]
 pub struct SegTree<T, M> {
+    n: usize,
     N: usize,
     a: Vec<T>,
     _m: PhantomData<M>,
@@ -17,6 +18,7 @@ where
     pub fn new(n: usize) -> Self {
         let (N, a) = Self::alloc(n);
         Self {
+            n,
             N,
             a,
             _m: PhantomData,
@@ -29,6 +31,7 @@ where
             a[i] = T::binop(a[i << 1], a[i << 1 | 1]);
         }
         Self {
+            n: leaf.len(),
             N,
             a,
             _m: PhantomData,
@@ -67,6 +70,88 @@ where
         }
         T::binop(x, y)
     }
+    /// Max `r` s.t. `pred(sum(l..r))` holds.
+    ///
+    /// Require `pred(ID)` and `pred` monotone, i.e. once false, false for longer range.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*).
+    pub fn max_right<P>(&self, l: usize, mut pred: P) -> usize
+    where
+        P: FnMut(T) -> bool,
+    {
+        debug_assert!(l <= self.n && pred(T::ID));
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.N;
+        let mut sum = T::ID;
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let x = T::binop(sum, self.a[l]);
+            if !pred(x) {
+                while l < self.N {
+                    l <<= 1;
+                    let x = T::binop(sum, self.a[l]);
+                    if pred(x) {
+                        sum = x;
+                        l += 1;
+                    }
+                }
+                return l - self.N;
+            }
+            sum = x;
+            l += 1;
+            if l.is_power_of_two() {
+                break;
+            }
+        }
+        self.n
+    }
+    /// Min `l` s.t. `pred(sum(l..r))` holds.
+    ///
+    /// Require `pred(ID)` and `pred` monotone, i.e. once false, false for longer range.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*).
+    pub fn min_left<P>(&self, r: usize, mut pred: P) -> usize
+    where
+        P: FnMut(T) -> bool,
+    {
+        debug_assert!(r <= self.n && pred(T::ID));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.N;
+        let mut sum = T::ID;
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let x = T::binop(self.a[r], sum);
+            if !pred(x) {
+                while r < self.N {
+                    r = r << 1 | 1;
+                    let x = T::binop(self.a[r], sum);
+                    if pred(x) {
+                        sum = x;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.N;
+            }
+            sum = x;
+            if r.is_power_of_two() {
+                break;
+            }
+        }
+        0
+    }
     #[inline]
     fn pull(&mut self, i: usize) {
         self.a[i] = T::binop(self.a[i << 1], self.a[i << 1 | 1]);
@@ -87,3 +172,6 @@ where
         (N, vec![T::ID; N << 1])
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,43 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+
+#[test]
+fn max_right_min_left() {
+    let rng = Rng::new();
+    for n in 0..40 {
+        let a: Vec<i64> = (0..n).map(|_| (rng.gen() % 10) as i64).collect();
+        let s = SegTree::<_, Add>::from(&a);
+        for _ in 0..50 {
+            let k = (rng.gen() % 60) as i64;
+            for l in 0..=n {
+                let mut r = l;
+                while r < n && a[l..=r].iter().sum::<i64>() <= k {
+                    r += 1;
+                }
+                assert_eq!(s.max_right(l, |x| x <= k), r);
+            }
+            for r in 0..=n {
+                let mut l = r;
+                while l > 0 && a[l - 1..r].iter().sum::<i64>() <= k {
+                    l -= 1;
+                }
+                assert_eq!(s.min_left(r, |x| x <= k), l);
+            }
+        }
+    }
+}
+
+#[test]
+fn first_greater() {
+    let a = [3, 1, 4, 1, 5, 9, 2, 6];
+    let mut s = SegTree::<i32, Max>::new(a.len());
+    for (i, &x) in a.iter().enumerate() {
+        s.set(i, x);
+    }
+    assert_eq!(s.max_right(0, |x| x < 4), 2);
+    assert_eq!(s.max_right(3, |x| x < 9), 5);
+    assert_eq!(s.max_right(6, |x| x < 9), 8);
+    assert_eq!(s.min_left(8, |x| x < 9), 6);
+    assert_eq!(s.min_left(4, |x| x < 4), 3);
+}
@@ -40,6 +40,6 @@ where
 }
 
 #[inline]
-fn msb(n: usize) -> usize {
+pub(super) fn msb(n: usize) -> usize {
     8 * std::mem::size_of::<usize>() - 1 - n.leading_zeros() as usize
 }
@@ -1,19 +1,26 @@
 use super::node::*;
-use crate::algebra::Monoid;
+use crate::algebra::{Affine, Monoid};
 use crate::core::Rng;
-use std::{ops::Range, ptr};
+use std::{mem, ops::Range, ptr};
 
-/// Lazy treap support flip range, insert/delete element, query range sum.
-pub struct TreapLazy<V, F> {
-    root: Edge<V, F>,
-    buf: Vec<NodeRef<V, F>>,
+type Slot<V, F, U, D, A> = Option<NodeRef<V, F, U, D, A>>;
+
+/// Lazy treap support flip range, apply action on range, insert/delete element, query range sum.
+///
+/// `(V,F)` is monoid, `(U,D)` is monoid action defined by `A(V,U)->V`, same as `SegLazy`.
+/// Action is optional, default to trivial `()`.
+pub struct TreapLazy<V, F, U = (), D = (), A = ()> {
+    root: Edge<V, F, U, D, A>,
+    /// Node of each original index, `None` if moved out by `split_off`.
+    buf: Vec<Slot<V, F, U, D, A>>,
     length: u32,
     rng: Rng,
 }
 
-impl<V, F> TreapLazy<V, F>
+impl<V, F, U, D, A> TreapLazy<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
 {
     pub fn new() -> Self {
         Self {
@@ -23,11 +30,30 @@ where
             rng: Rng::new(),
         }
     }
+    /// *O*(*n*) build.
+    pub fn from_slice(a: &[V]) -> Self {
+        let mut t = Self::new();
+        let nodes: Vec<_> = a
+            .iter()
+            .enumerate()
+            .map(|(i, &val)| NodeRef::new(i as u32, val, t.rng.gen()))
+            .collect();
+        t.length = a.len() as u32;
+        t.root = Edge::build(&nodes);
+        t.buf = nodes.into_iter().map(Some).collect();
+        t
+    }
+    pub fn len(&self) -> usize {
+        self.root.len() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     /// equiv to `a.insert(n, val)`
     pub fn insert_at(&mut self, n: usize, val: V) {
         let ptr = NodeRef::new(self.length, val, self.rng.gen());
         self.length += 1;
-        self.buf.push(ptr);
+        self.buf.push(Some(ptr));
         self.root.insert_at(n as u32, ptr);
     }
     pub fn delete_at(&mut self, n: usize) {
@@ -38,15 +64,65 @@ where
         let Range { start, end } = r;
         self.root.rev(start as u32, end as u32);
     }
+    /// equiv to `a[l..r].map(|x| A(x, u))`
+    pub fn apply(&mut self, r: Range<usize>, u: U) {
+        let Range { start, end } = r;
+        self.root.apply_range(start as u32, end as u32, u);
+    }
+    /// Cut `a[l..r]` and paste it at `p` of the rest.
+    ///
+    /// equiv to `let b = a.drain(l..r).collect(); a.splice(p..p, b)`
+    pub fn cut_paste(&mut self, r: Range<usize>, p: usize) {
+        let Range { start, end } = r;
+        let (l, r) = self.root.split_at(end as u32);
+        let (l, m) = l.split_at(start as u32);
+        let (l, r) = l.merge(r).split_at(p as u32);
+        self.root = l.merge(m).merge(r);
+    }
+    /// Concatenate `other` to the end, its original index offset by count of `self`'s.
+    pub fn append(&mut self, mut other: Self) {
+        let buf = mem::take(&mut other.buf);
+        for mut u in buf.iter().flatten().copied() {
+            u.id += self.length;
+        }
+        self.length += other.length;
+        self.buf.extend(buf);
+        self.root = self.root.merge(other.root);
+    }
+    /// Split into `a[..at]` and returned `a[at..]`, with `moved`.
+    ///
+    /// Original indexes kept in `self` stay valid. Those moved are renumbered in `other` by
+    /// their old order, i.e. original index `moved[j]` becomes `j`, `moved` ascending.
+    ///
+    /// *O*(log *n* + *k* log *k*) for *k* = `len() - at`, to transfer ownership.
+    pub fn split_off(&mut self, at: usize) -> (Self, Vec<usize>) {
+        let (l, r) = self.root.split_at(at as u32);
+        self.root = l;
+        let mut moved = vec![];
+        r.for_each_node(&mut |u| moved.push(u.id as usize));
+        moved.sort_unstable();
+        let mut other = Self::new();
+        other.root = r;
+        other.buf = moved
+            .iter()
+            .enumerate()
+            .map(|(j, &i)| {
+                let mut u = self.buf[i].take();
+                u.iter_mut().for_each(|u| u.id = j as u32);
+                u
+            })
+            .collect();
+        other.length = moved.len() as u32;
+        (other, moved)
+    }
     pub fn sum(&mut self, r: Range<usize>) -> V {
         let Range { start, end } = r;
         self.root.summation(start as u32, end as u32)
     }
     /// Find current index of original index.
     pub fn find_index(&self, n: usize) -> usize {
-        self.buf[n].find_index()
+        self.buf[n].expect("moved by split_off").find_index()
     }
-
     /// in-order dfs, call on `(id, val)`.
     pub fn walk<G>(&mut self, mut g: G)
     where
@@ -55,9 +131,9 @@ where
         self.root.walk(&mut g);
     }
 }
-impl<V, F> Drop for TreapLazy<V, F> {
+impl<V, F, U, D, A> Drop for TreapLazy<V, F, U, D, A> {
     fn drop(&mut self) {
-        while let Some(u) = self.buf.pop() {
+        for u in self.buf.drain(..).flatten() {
             unsafe { ptr::drop_in_place(u.into_raw()) };
         }
     }
@@ -0,0 +1,245 @@
+use crate::algebra::Monoid;
+use crate::core::Rng;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+type Edge<K, V, M> = Option<Box<Node<K, V, M>>>;
+struct Node<K, V, M> {
+    priority: u64,
+    children: [Edge<K, V, M>; 2],
+    size: usize,
+    key: K,
+    val: V,
+    sum: V,
+    _m: PhantomData<M>,
+}
+
+fn size<K, V, M>(u: &Edge<K, V, M>) -> usize {
+    u.as_ref().map_or(0, |u| u.size)
+}
+fn sum<K, V, M>(u: &Edge<K, V, M>) -> V
+where
+    V: Monoid<M> + Copy,
+{
+    u.as_ref().map_or(V::ID, |u| u.sum)
+}
+impl<K, V, M> Node<K, V, M>
+where
+    V: Monoid<M> + Copy,
+{
+    fn pull(&mut self) {
+        let [l, r] = &self.children;
+        self.size = size(l) + size(r) + 1;
+        self.sum = V::binop(V::binop(sum(l), self.val), sum(r));
+    }
+}
+/// Split into `p(key)` true and false parts, `p` must be monotone on keys.
+fn split_by<K, V, M, P>(u: Edge<K, V, M>, p: &P) -> (Edge<K, V, M>, Edge<K, V, M>)
+where
+    V: Monoid<M> + Copy,
+    P: Fn(&K) -> bool,
+{
+    match u {
+        None => (None, None),
+        Some(mut u) => {
+            if p(&u.key) {
+                let (l, r) = split_by(u.children[1].take(), p);
+                u.children[1] = l;
+                u.pull();
+                (Some(u), r)
+            } else {
+                let (l, r) = split_by(u.children[0].take(), p);
+                u.children[0] = r;
+                u.pull();
+                (l, Some(u))
+            }
+        }
+    }
+}
+/// Require all keys of `l` less than `r`'s.
+fn merge<K, V, M>(l: Edge<K, V, M>, r: Edge<K, V, M>) -> Edge<K, V, M>
+where
+    V: Monoid<M> + Copy,
+{
+    match (l, r) {
+        (None, r) => r,
+        (l, None) => l,
+        (Some(mut l), Some(mut r)) => {
+            if l.priority < r.priority {
+                r.children[0] = merge(Some(l), r.children[0].take());
+                r.pull();
+                Some(r)
+            } else {
+                l.children[1] = merge(l.children[1].take(), Some(r));
+                l.pull();
+                Some(l)
+            }
+        }
+    }
+}
+
+/// Key ordered treap, with augmented monoid sum of values.
+///
+/// # Time complexity
+///
+/// Expected *O*(log *n*) per operation.
+pub struct TreapMap<K, V, M> {
+    root: Edge<K, V, M>,
+    rng: Rng,
+}
+
+impl<K, V, M> Default for TreapMap<K, V, M>
+where
+    K: Ord + Copy,
+    V: Monoid<M> + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, M> TreapMap<K, V, M>
+where
+    K: Ord + Copy,
+    V: Monoid<M> + Copy,
+{
+    pub fn new() -> Self {
+        Self::from_edge(None)
+    }
+    fn from_edge(root: Edge<K, V, M>) -> Self {
+        Self {
+            root,
+            rng: Rng::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    /// Insert or replace, ret: old value.
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        let (l, r) = split_by(self.root.take(), &|k: &K| *k < key);
+        let (m, r) = split_by(r, &|k: &K| *k <= key);
+        let old = m.map(|u| u.val);
+        let m = Some(Box::new(Node {
+            priority: self.rng.gen(),
+            children: [None, None],
+            size: 1,
+            key,
+            val,
+            sum: val,
+            _m: PhantomData,
+        }));
+        self.root = merge(merge(l, m), r);
+        old
+    }
+    /// ret: removed value.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (l, r) = split_by(self.root.take(), &|k: &K| k < key);
+        let (m, r) = split_by(r, &|k: &K| k <= key);
+        self.root = merge(l, r);
+        m.map(|u| u.val)
+    }
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut u = &self.root;
+        while let Some(v) = u {
+            if *key < v.key {
+                u = &v.children[0];
+            } else if v.key < *key {
+                u = &v.children[1];
+            } else {
+                return Some(v.val);
+            }
+        }
+        None
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+    /// First entry with key `>= key`.
+    pub fn lower_bound(&self, key: &K) -> Option<(K, V)> {
+        let mut u = &self.root;
+        let mut res = None;
+        while let Some(v) = u {
+            if v.key < *key {
+                u = &v.children[1];
+            } else {
+                res = Some((v.key, v.val));
+                u = &v.children[0];
+            }
+        }
+        res
+    }
+    /// Count of keys `< key`.
+    pub fn rank(&self, key: &K) -> usize {
+        let mut u = &self.root;
+        let mut res = 0;
+        while let Some(v) = u {
+            if v.key < *key {
+                res += size(&v.children[0]) + 1;
+                u = &v.children[1];
+            } else {
+                u = &v.children[0];
+            }
+        }
+        res
+    }
+    /// `n`-th(0-based) entry by key order.
+    pub fn kth(&self, n: usize) -> Option<(K, V)> {
+        let mut u = &self.root;
+        let mut n = n;
+        while let Some(v) = u {
+            let s = size(&v.children[0]);
+            if n < s {
+                u = &v.children[0];
+            } else if n == s {
+                return Some((v.key, v.val));
+            } else {
+                n -= s + 1;
+                u = &v.children[1];
+            }
+        }
+        None
+    }
+    /// Sum of values with key in range, by key order.
+    pub fn sum(&mut self, r: Range<K>) -> V {
+        let Range { start, end } = r;
+        if start >= end {
+            return V::ID;
+        }
+        let (l, r) = split_by(self.root.take(), &|k: &K| *k < start);
+        let (m, r) = split_by(r, &|k: &K| *k < end);
+        let res = sum(&m);
+        self.root = merge(merge(l, m), r);
+        res
+    }
+    /// = `sum` of all.
+    pub fn all(&self) -> V {
+        sum(&self.root)
+    }
+    /// Split into keys `< key` and keys `>= key`.
+    pub fn split_by_key(self, key: &K) -> (Self, Self) {
+        let (l, r) = split_by(self.root, &|k: &K| k < key);
+        (Self::from_edge(l), Self::from_edge(r))
+    }
+    /// Concatenate, require all keys of `self` less than `r`'s.
+    pub fn merge(self, r: Self) -> Self {
+        Self::from_edge(merge(self.root, r.root))
+    }
+    /// in-order dfs, call on `(key, val)`.
+    pub fn walk<G>(&self, mut g: G)
+    where
+        G: FnMut(K, V),
+    {
+        fn dfs<K: Copy, V: Copy, M, G: FnMut(K, V)>(u: &Edge<K, V, M>, g: &mut G) {
+            if let Some(u) = u {
+                dfs(&u.children[0], g);
+                g(u.key, u.val);
+                dfs(&u.children[1], g);
+            }
+        }
+        dfs(&self.root, &mut g);
+    }
+}
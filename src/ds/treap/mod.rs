@@ -1,5 +1,6 @@
-//! Non-rotated lazy treap, for reverse range operation.
+//! Non-rotated treaps, implicit key for reverse range and range action operation, or ordered by key.
 pub mod lazy;
+mod map;
 mod node;
 pub mod persistent;
 mod pnode;
@@ -7,7 +8,31 @@ mod pnode;
 //mod traits;
 
 pub use self::lazy::TreapLazy;
+pub use self::map::TreapMap;
 pub use self::persistent::TreapPersistent;
 
+/// Cartesian tree of priorities, larger on top, for *O*(*n*) building.
+///
+/// ret: `(root, children)`.
+fn cartesian(p: &[u64]) -> (Option<usize>, Vec<[Option<usize>; 2]>) {
+    let mut c = vec![[None; 2]; p.len()];
+    let mut stack: Vec<usize> = vec![];
+    for i in 0..p.len() {
+        let mut last = None;
+        while let Some(&j) = stack.last() {
+            if p[j] >= p[i] {
+                break;
+            }
+            last = stack.pop();
+        }
+        c[i][0] = last;
+        if let Some(&j) = stack.last() {
+            c[j][1] = Some(i);
+        }
+        stack.push(i);
+    }
+    (stack.first().copied(), c)
+}
+
 #[cfg(test)]
 mod tests;
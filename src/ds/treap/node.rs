@@ -5,21 +5,24 @@ use std::{
     ptr::NonNull,
 };
 
-use crate::algebra::Monoid;
+use crate::algebra::{Affine, Monoid};
 
-pub struct Node<V, F> {
+pub struct Node<V, F, U, D, A> {
     priority: u64,
-    children: [Edge<V, F>; 2],
-    parent: Edge<V, F>,
+    children: [Edge<V, F, U, D, A>; 2],
+    parent: Edge<V, F, U, D, A>,
     pub id: u32,
     size: u32,
     pub val: V,
     sum: V,
     flip: u8,
+    tag: U,
+    _m: PhantomData<(D, A)>,
 }
-impl<V, F> Node<V, F>
+impl<V, F, U, D, A> Node<V, F, U, D, A>
 where
     V: Monoid<F> + Copy,
+    U: Monoid<D>,
 {
     fn new(id: u32, val: V, priority: u64) -> Self {
         Self {
@@ -31,13 +34,15 @@ where
             val,
             sum: val,
             flip: 0,
+            tag: U::ID,
+            _m: PhantomData,
         }
     }
 }
-type BoxedNode<V, F> = NonNull<Node<V, F>>;
+type BoxedNode<V, F, U, D, A> = NonNull<Node<V, F, U, D, A>>;
 
-pub struct NodeRef<V, F> {
-    node: BoxedNode<V, F>,
+pub struct NodeRef<V, F, U, D, A> {
+    node: BoxedNode<V, F, U, D, A>,
     _f: PhantomData<F>,
 }
 enum Child {
@@ -45,17 +50,17 @@ enum Child {
     Right,
     Orphan,
 }
-impl<V, F> NodeRef<V, F> {
-    pub fn into_raw(&self) -> *mut Node<V, F> {
+impl<V, F, U, D, A> NodeRef<V, F, U, D, A> {
+    pub fn into_raw(&self) -> *mut Node<V, F, U, D, A> {
         self.node.as_ptr()
     }
 
     #[inline]
-    fn left(&self) -> &Edge<V, F> {
+    fn left(&self) -> &Edge<V, F, U, D, A> {
         &self.children[0]
     }
     #[inline]
-    fn right(&self) -> &Edge<V, F> {
+    fn right(&self) -> &Edge<V, F, U, D, A> {
         &self.children[1]
     }
     #[inline]
@@ -68,20 +73,8 @@ impl<V, F> NodeRef<V, F> {
             },
         }
     }
-    /// Push down lazy tag.
     #[inline]
-    fn push(&self) {
-        let mut u = *self;
-        if u.flip != 0 {
-            u.children.swap(0, 1);
-            u.flip = 0;
-            for v in u.children.iter() {
-                v.edge.map(|mut v| v.flip ^= 1);
-            }
-        }
-    }
-    #[inline]
-    fn detach(&mut self, n: usize) -> Edge<V, F> {
+    fn detach(&mut self, n: usize) -> Edge<V, F, U, D, A> {
         let res = self.children[n];
         self.children[n] = Edge::none();
         if let Some(mut v) = res.edge {
@@ -90,15 +83,16 @@ impl<V, F> NodeRef<V, F> {
         res
     }
     #[inline]
-    fn attach(&mut self, n: usize, v: Edge<V, F>) {
+    fn attach(&mut self, n: usize, v: Edge<V, F, U, D, A>) {
         debug_assert!(self.children[n].edge.is_none());
         v.edge.map(|mut v| v.parent = Edge::from(*self));
         self.children[n] = v;
     }
 }
-impl<V, F> NodeRef<V, F>
+impl<V, F, U, D, A> NodeRef<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
 {
     pub fn new(id: u32, val: V, priority: u64) -> Self {
         let ptr: *mut _ = Box::into_raw(Box::new(Node::new(id, val, priority)));
@@ -107,6 +101,32 @@ where
             _f: PhantomData,
         }
     }
+    /// Push down lazy tags.
+    #[inline]
+    fn push(&self) {
+        let mut u = *self;
+        if u.flip != 0 {
+            u.children.swap(0, 1);
+            u.flip = 0;
+            for v in u.children.iter() {
+                v.edge.map(|mut v| v.flip ^= 1);
+            }
+        }
+        if u.tag != U::ID {
+            for v in u.children.iter() {
+                v.apply(u.tag);
+            }
+            u.tag = U::ID;
+        }
+    }
+    /// Apply action on whole subtree, lazily.
+    #[inline]
+    fn apply(&self, t: U) {
+        let mut u = *self;
+        u.val = V::affine(u.val, t);
+        u.sum = V::affine(u.sum, t);
+        u.tag = U::binop(u.tag, t);
+    }
     /// Update current node's sum.
     #[inline]
     fn pull(&self) {
@@ -138,33 +158,50 @@ where
     }
 }
 
-pub struct Edge<V, F> {
-    edge: Option<NodeRef<V, F>>,
+pub struct Edge<V, F, U, D, A> {
+    edge: Option<NodeRef<V, F, U, D, A>>,
 }
 
-impl<V, F> Edge<V, F> {
+impl<V, F, U, D, A> Edge<V, F, U, D, A> {
     pub fn none() -> Self {
         Self { edge: None }
     }
-    fn len(&self) -> u32 {
+    pub fn len(&self) -> u32 {
         self.edge.map_or(0, |u| u.size)
     }
-    fn push(&self) {
-        if let Some(u) = self.edge {
-            u.push();
-        }
-    }
     fn is_some(&self) -> bool {
         self.edge.is_some()
     }
     fn is_none(&self) -> bool {
         self.edge.is_none()
     }
+    /// Call on each node of subtree, in arbitrary order.
+    pub fn for_each_node<G>(&self, g: &mut G)
+    where
+        G: FnMut(NodeRef<V, F, U, D, A>),
+    {
+        if let Some(u) = self.edge {
+            u.left().for_each_node(g);
+            u.right().for_each_node(g);
+            g(u);
+        }
+    }
 }
-impl<V, F> Edge<V, F>
+impl<V, F, U, D, A> Edge<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
 {
+    fn push(&self) {
+        if let Some(u) = self.edge {
+            u.push();
+        }
+    }
+    fn apply(&self, t: U) {
+        if let Some(u) = self.edge {
+            u.apply(t);
+        }
+    }
     fn pull(&self) {
         if let Some(u) = self.edge {
             u.pull()
@@ -223,7 +260,7 @@ where
         }
     }
     /// new single node
-    pub fn insert_at(&mut self, n: u32, p: NodeRef<V, F>) {
+    pub fn insert_at(&mut self, n: u32, p: NodeRef<V, F, U, D, A>) {
         let (l, r) = self.split_at(n);
         *self = l.merge(Self::from(p)).merge(r);
     }
@@ -241,6 +278,15 @@ where
         m.edge.map(|mut u| u.flip ^= 1);
         *self = l.merge(m).merge(r);
     }
+    pub fn apply_range(&mut self, start: u32, end: u32, t: U) {
+        if start >= end {
+            return;
+        }
+        let (l, r) = self.split_at(end);
+        let (l, m) = l.split_at(start);
+        m.apply(t);
+        *self = l.merge(m).merge(r);
+    }
     pub fn summation(&mut self, start: u32, end: u32) -> V {
         if start >= end {
             return V::ID;
@@ -262,38 +308,54 @@ where
             u.right().walk(g);
         }
     }
+    /// Link nodes in order, shaped by cartesian tree of priorities, *O*(*n*).
+    pub fn build(nodes: &[NodeRef<V, F, U, D, A>]) -> Self {
+        let p: Vec<u64> = nodes.iter().map(|u| u.priority).collect();
+        let (root, c) = super::cartesian(&p);
+        root.map_or(Self::none(), |i| Self::link(nodes, &c, i))
+    }
+    fn link(nodes: &[NodeRef<V, F, U, D, A>], c: &[[Option<usize>; 2]], i: usize) -> Self {
+        let mut u = nodes[i];
+        for (k, &j) in c[i].iter().enumerate() {
+            if let Some(j) = j {
+                u.attach(k, Self::link(nodes, c, j));
+            }
+        }
+        u.pull();
+        Self::from(u)
+    }
 }
-impl<V, F> PartialEq for NodeRef<V, F> {
+impl<V, F, U, D, A> PartialEq for NodeRef<V, F, U, D, A> {
     fn eq(&self, other: &Self) -> bool {
         self.node == other.node
     }
 }
-impl<V, F> Eq for NodeRef<V, F> {}
-impl<V, F> Clone for NodeRef<V, F> {
+impl<V, F, U, D, A> Eq for NodeRef<V, F, U, D, A> {}
+impl<V, F, U, D, A> Clone for NodeRef<V, F, U, D, A> {
     fn clone(&self) -> Self {
         *self
     }
 }
-impl<V, F> Copy for NodeRef<V, F> {}
-impl<V, F> Deref for NodeRef<V, F> {
-    type Target = Node<V, F>;
-    fn deref(&self) -> &Node<V, F> {
+impl<V, F, U, D, A> Copy for NodeRef<V, F, U, D, A> {}
+impl<V, F, U, D, A> Deref for NodeRef<V, F, U, D, A> {
+    type Target = Node<V, F, U, D, A>;
+    fn deref(&self) -> &Node<V, F, U, D, A> {
         unsafe { self.node.as_ref() }
     }
 }
-impl<V, F> DerefMut for NodeRef<V, F> {
-    fn deref_mut(&mut self) -> &mut Node<V, F> {
+impl<V, F, U, D, A> DerefMut for NodeRef<V, F, U, D, A> {
+    fn deref_mut(&mut self) -> &mut Node<V, F, U, D, A> {
         unsafe { self.node.as_mut() }
     }
 }
-impl<V, F> Clone for Edge<V, F> {
+impl<V, F, U, D, A> Clone for Edge<V, F, U, D, A> {
     fn clone(&self) -> Self {
         *self
     }
 }
-impl<V, F> Copy for Edge<V, F> {}
-impl<V, F> From<NodeRef<V, F>> for Edge<V, F> {
-    fn from(u: NodeRef<V, F>) -> Self {
+impl<V, F, U, D, A> Copy for Edge<V, F, U, D, A> {}
+impl<V, F, U, D, A> From<NodeRef<V, F, U, D, A>> for Edge<V, F, U, D, A> {
+    fn from(u: NodeRef<V, F, U, D, A>) -> Self {
         Self { edge: Some(u) }
     }
 }
@@ -1,21 +1,27 @@
 use super::pnode::*;
-use crate::algebra::Monoid;
+use crate::algebra::{Affine, Monoid};
 use crate::core::Rng;
 use std::ops::Range;
 
-/// Peresistent and lazy treap support flip range, insert/delete element, query range sum.
-pub struct TreapPersistent<V, F> {
-    pub versions: Vec<Edge<V, F>>,
+/// Peresistent and lazy treap support flip range, apply action on range, insert/delete element, query range sum.
+///
+/// `(V,F)` is monoid, `(U,D)` is monoid action defined by `A(V,U)->V`, same as `SegLazy`.
+/// Action is optional, default to trivial `()`.
+pub struct TreapPersistent<V, F, U = (), D = (), A = ()> {
+    pub versions: Vec<Edge<V, F, U, D, A>>,
     rng: Rng,
 }
 
-impl<V, F> TreapPersistent<V, F>
+impl<V, F, U, D, A> TreapPersistent<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
     F: Clone,
+    D: Clone,
+    A: Clone,
 {
-    fn singleton(&self, val: V) -> Edge<V, F> {
-        Edge::singleton(val, self.rng.gen())
+    fn singleton(&self, val: V) -> Edge<V, F, U, D, A> {
+        Edge::singleton(val)
     }
     pub fn new() -> Self {
         Self {
@@ -32,12 +38,19 @@ where
             rng: Rng::new(),
         }
     }
-    fn action(&mut self, ver: usize, op: TreapOp<Edge<V, F>>) -> Result<V> {
+    fn action(&mut self, ver: usize, op: TreapOp<Edge<V, F, U, D, A>, U>) -> Result<V> {
         let mut root = self.versions[ver].clone();
         let res = root.action(op);
         self.versions.push(root);
         res
     }
+    /// *O*(*n*) build as version `0`.
+    pub fn from_slice(a: &[V]) -> Self {
+        let mut t = Self::new();
+        let p: Vec<u64> = a.iter().map(|_| t.rng.gen()).collect();
+        t.versions[0] = build(a, &p);
+        t
+    }
     /// equiv to `a.insert(n, val)`
     pub fn insert_at(&mut self, ver: usize, n: usize, val: V) {
         self.action(ver, TreapOp::Insert(n, self.singleton(val)));
@@ -52,6 +65,32 @@ where
     pub fn rev(&mut self, ver: usize, range: Range<usize>) {
         self.action(ver, TreapOp::Rev(range));
     }
+    /// equiv to `a[l..r].map(|x| A(x, u))`
+    pub fn apply(&mut self, ver: usize, range: Range<usize>, u: U) {
+        self.action(ver, TreapOp::Apply(range, u));
+    }
+    /// Cut `a[l..r]` and paste it at `p` of the rest, ret: new version.
+    ///
+    /// equiv to `let b = a.drain(l..r).collect(); a.splice(p..p, b)`
+    pub fn cut_paste(&mut self, ver: usize, range: Range<usize>, p: usize) -> usize {
+        let (l, m, r) = self.versions[ver].clone().split_range(range);
+        let (l, r) = l.merge(r).split_at(p);
+        self.versions.push(l.merge_triple(m, r));
+        self.versions.len() - 1
+    }
+    /// Concatenate version `x` and `y`, ret: new version.
+    pub fn concat(&mut self, x: usize, y: usize) -> usize {
+        let root = self.versions[x].clone().merge(self.versions[y].clone());
+        self.versions.push(root);
+        self.versions.len() - 1
+    }
+    /// Split into `a[..at]` and `a[at..]`, ret: two new versions.
+    pub fn split_at(&mut self, ver: usize, at: usize) -> (usize, usize) {
+        let (l, r) = self.versions[ver].clone().split_at(at);
+        self.versions.push(l);
+        self.versions.push(r);
+        (self.versions.len() - 2, self.versions.len() - 1)
+    }
     pub fn sum(&mut self, ver: usize, range: Range<usize>) -> V {
         match self.action(ver, TreapOp::Sum(range)) {
             Result::Sum(res) => res,
@@ -60,10 +99,13 @@ where
     }
 }
 pub use super::pnode::walk;
-pub fn to_vec<V, F>(u: &mut Edge<V, F>) -> Vec<V>
+pub fn to_vec<V, F, U, D, A>(u: &mut Edge<V, F, U, D, A>) -> Vec<V>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
     F: Clone,
+    D: Clone,
+    A: Clone,
 {
     let mut res = vec![];
     walk(u, &mut |x| {
@@ -1,54 +1,74 @@
-use crate::algebra::Monoid;
+use crate::algebra::{Affine, Monoid};
+use crate::core::Rng;
 use std::{marker::PhantomData, mem, ops::Range, rc::Rc};
 
-pub type NodeRef<V, F> = Rc<Node<V, F>>;
-pub type Edge<V, F> = Option<NodeRef<V, F>>;
+thread_local! {
+    /// Source of merge choices, nodes carry no priority as they may be shared.
+    static RNG: Rng = Rng::new();
+}
+
+pub type NodeRef<V, F, U, D, A> = Rc<Node<V, F, U, D, A>>;
+pub type Edge<V, F, U, D, A> = Option<NodeRef<V, F, U, D, A>>;
 #[derive(Clone)]
-pub struct Node<V, F> {
-    priority: u64,
-    children: [Edge<V, F>; 2],
+pub struct Node<V, F, U, D, A> {
+    children: [Edge<V, F, U, D, A>; 2],
     len: usize,
     pub val: V,
     sum: V,
     flip: u8,
-    _f: PhantomData<F>,
+    tag: U,
+    _m: PhantomData<(F, D, A)>,
 }
-impl<V, F> Node<V, F>
+impl<V, F, U, D, A> Node<V, F, U, D, A>
 where
     V: Copy,
+    U: Monoid<D>,
 {
-    pub fn new(val: V, priority: u64) -> Self {
+    pub fn new(val: V) -> Self {
         Self {
-            priority,
             children: [None, None],
             len: 1,
             val,
             sum: val,
             flip: 0,
-            _f: PhantomData,
+            tag: U::ID,
+            _m: PhantomData,
         }
     }
 }
 
 pub trait TreapInner {
     type E;
+    type U;
     fn flip(&mut self);
+    fn apply(&mut self, t: Self::U);
     fn push(&mut self);
     fn pull(&mut self);
     fn detach(&mut self, n: usize) -> Self::E;
     fn attach(&mut self, n: usize, e: Self::E);
 }
-impl<V, F> TreapInner for NodeRef<V, F>
+impl<V, F, U, D, A> TreapInner for NodeRef<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
     F: Clone,
+    D: Clone,
+    A: Clone,
 {
-    type E = Edge<V, F>;
+    type E = Edge<V, F, U, D, A>;
+    type U = U;
     #[inline]
     fn flip(&mut self) {
         Rc::make_mut(self).flip ^= 1;
     }
     #[inline]
+    fn apply(&mut self, t: U) {
+        let u = Rc::make_mut(self);
+        u.val = V::affine(u.val, t);
+        u.sum = V::affine(u.sum, t);
+        u.tag = U::binop(u.tag, t);
+    }
+    #[inline]
     fn push(&mut self) {
         let u = self;
         if u.flip != 0 {
@@ -59,6 +79,13 @@ where
                 v.as_mut().map(|v| Rc::make_mut(v).flip ^= 1);
             }
         }
+        if u.tag != U::ID {
+            let u = Rc::make_mut(u);
+            for v in u.children.iter_mut().flatten() {
+                v.apply(u.tag);
+            }
+            u.tag = U::ID;
+        }
     }
     #[inline]
     fn pull(&mut self) {
@@ -83,16 +110,18 @@ where
     }
 }
 
-pub enum TreapOp<E, R = Range<usize>> {
+pub enum TreapOp<E, U = (), R = Range<usize>> {
     Insert(usize, E),
     Remove(R),
     Rev(R),
+    Apply(R, U),
     Sum(R),
 }
-enum Action<E> {
+enum Action<E, U> {
     Insert(E),
     Remove,
     Rev,
+    Apply(U),
     Sum,
 }
 pub enum Result<V> {
@@ -105,15 +134,17 @@ where
     Self: Sized + Clone,
 {
     type V;
+    type U;
     type Ptr;
     fn empty() -> Self;
-    fn singleton(val: Self::V, priority: u64) -> Self;
+    fn singleton(val: Self::V) -> Self;
     // base information
     fn len(&self) -> usize;
     fn val(&self) -> Self::V;
     fn sum(&self) -> Self::V;
     // base operation
     fn flip(&mut self);
+    fn apply(&mut self, t: Self::U);
     fn push(&mut self);
     fn pull(&mut self);
     fn split_at(self, n: usize) -> (Self, Self);
@@ -130,7 +161,7 @@ where
     fn merge_triple(self, m: Self, r: Self) -> Self {
         self.merge(m).merge(r)
     }
-    fn action(&mut self, op: TreapOp<Self, Range<usize>>) -> Result<Self::V> {
+    fn action(&mut self, op: TreapOp<Self, Self::U, Range<usize>>) -> Result<Self::V> {
         let mut u = Self::empty();
         mem::swap(&mut u, self);
         //let u = self.clone();
@@ -139,6 +170,7 @@ where
             TreapOp::Insert(n, x) => (n..n, Action::Insert(x)),
             TreapOp::Remove(r) => (r, Action::Remove),
             TreapOp::Rev(r) => (r, Action::Rev),
+            TreapOp::Apply(r, t) => (r, Action::Apply(t)),
             TreapOp::Sum(r) => (r, Action::Sum),
         };
 
@@ -151,6 +183,10 @@ where
                 m.flip();
                 (m, Result::None)
             }
+            Action::Apply(t) => {
+                m.apply(t);
+                (m, Result::None)
+            }
             Action::Sum => {
                 let res = m.sum();
                 (m, Result::Sum(res))
@@ -173,13 +209,17 @@ where
     //    self.action(TreapOp::Rev(range))
     //}    fn singleton(val: V, priority: u64) -> Self {
 }
-impl<V, F> Treap for Edge<V, F>
+impl<V, F, U, D, A> Treap for Edge<V, F, U, D, A>
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
     F: Clone,
+    D: Clone,
+    A: Clone,
 {
     type V = V;
-    type Ptr = NodeRef<V, F>;
+    type U = U;
+    type Ptr = NodeRef<V, F, U, D, A>;
     #[inline]
     fn len(&self) -> usize {
         self.as_ref().map_or(0, |u| u.len)
@@ -189,8 +229,8 @@ where
         None
     }
     #[inline]
-    fn singleton(val: V, priority: u64) -> Self {
-        Some(Self::Ptr::new(Node::new(val, priority)))
+    fn singleton(val: V) -> Self {
+        Some(Self::Ptr::new(Node::new(val)))
     }
     #[inline]
     fn val(&self) -> V {
@@ -205,6 +245,12 @@ where
         self.as_mut().map(|u| u.flip());
     }
     #[inline]
+    fn apply(&mut self, t: U) {
+        if let Some(u) = self {
+            u.apply(t);
+        }
+    }
+    #[inline]
     fn push(&mut self) {
         self.as_mut().map(|u| u.push());
     }
@@ -243,7 +289,10 @@ where
             (Some(mut l), Some(mut r)) => {
                 l.push();
                 r.push();
-                let mut res = if l.priority < r.priority {
+                // root chosen with probability by size, so that merging a version with
+                // itself, where each node meets its own copy, stays balanced
+                let k = RNG.with(|g| g.gen()) % (l.len + r.len) as u64;
+                let mut res = if k >= l.len as u64 {
                     // l x-r-y => lx -r- y
                     //r.children[0] = Self::from(l).merge(*r.left());
                     let lx = Some(l).merge(r.detach(0));
@@ -263,10 +312,43 @@ where
     }
 }
 
-pub fn walk<V, F, G>(u: &mut Edge<V, F>, g: &mut G)
+/// Link `a` in order, shaped by cartesian tree of priorities `p`, *O*(*n*).
+pub fn build<V, F, U, D, A>(a: &[V], p: &[u64]) -> Edge<V, F, U, D, A>
+where
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
+    F: Clone,
+    D: Clone,
+    A: Clone,
+{
+    let (root, c) = super::cartesian(p);
+    root.and_then(|i| link(a, &c, i))
+}
+fn link<V, F, U, D, A>(a: &[V], c: &[[Option<usize>; 2]], i: usize) -> Edge<V, F, U, D, A>
+where
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
+    F: Clone,
+    D: Clone,
+    A: Clone,
+{
+    let mut u = NodeRef::new(Node::new(a[i]));
+    for (k, &j) in c[i].iter().enumerate() {
+        if let Some(j) = j {
+            u.attach(k, link(a, c, j));
+        }
+    }
+    u.pull();
+    Some(u)
+}
+
+pub fn walk<V, F, U, D, A, G>(u: &mut Edge<V, F, U, D, A>, g: &mut G)
 where
-    V: Monoid<F> + Copy,
+    V: Monoid<F> + Copy + Affine<U, A>,
+    U: Monoid<D> + Copy + PartialEq,
     F: Clone,
+    D: Clone,
+    A: Clone,
     G: FnMut(V),
 {
     if let Some(u) = u {
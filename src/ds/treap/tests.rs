@@ -1,4 +1,6 @@
 // verified, luogu: p3165 p3391 p5055.
+use super::lazy::*;
+use super::map::*;
 use super::persistent::*;
 use super::pnode::*;
 use crate::algebra::*;
@@ -138,3 +140,269 @@ fn rev() {
     assert_eq!(t.sum(12, 3..4), 5);
     assert_eq!(t.sum(10, 3..4), 3);
 }
+
+#[test]
+fn lazy_add_rev_min() {
+    affine_new!(impl F<i64> for i64, |x, u| x + u);
+    let mut t = TreapLazy::<i64, Min, i64, Add, F>::new();
+    let rng = Rng::new();
+    let mut a = vec![];
+    for _ in 0..2000 {
+        let n = a.len();
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        let x = (rng.gen() % 100) as i64 - 50;
+        match rng.gen() % 5 {
+            0 => {
+                t.insert_at(l, x);
+                a.insert(l, x);
+            }
+            1 if l < n => {
+                t.delete_at(l);
+                a.remove(l);
+            }
+            2 => {
+                t.rev(l..r);
+                a[l..r].reverse();
+            }
+            3 => {
+                t.apply(l..r, x);
+                a[l..r].iter_mut().for_each(|y| *y += x);
+            }
+            _ => {
+                let res = a[l..r]
+                    .iter()
+                    .copied()
+                    .min()
+                    .unwrap_or(<i64 as Monoid<Min>>::ID);
+                assert_eq!(t.sum(l..r), res);
+            }
+        }
+    }
+    let mut b = vec![];
+    t.walk(|_, x| b.push(x));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn persistent_add_rev() {
+    affine_new!(impl F<i64> for i64, |x, u| x + u);
+    let mut t = TreapPersistent::<i64, Max, i64, Add, F>::new();
+    let rng = Rng::new();
+    let mut a = vec![vec![]];
+    for _ in 0..500 {
+        let v = rng.gen() as usize % a.len();
+        let mut b: Vec<i64> = a[v].clone();
+        let n = b.len();
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        let x = (rng.gen() % 100) as i64 - 50;
+        match rng.gen() % 4 {
+            0 | 1 => {
+                t.insert_at(v, l, x);
+                b.insert(l, x);
+            }
+            2 => {
+                t.rev(v, l..r);
+                b[l..r].reverse();
+            }
+            _ => {
+                t.apply(v, l..r, x);
+                b[l..r].iter_mut().for_each(|y| *y += x);
+            }
+        }
+        a.push(b);
+    }
+    for (v, b) in a.iter().enumerate() {
+        assert_eq!(*b, to_vec(&mut t.versions[v]));
+    }
+    let v = (0..a.len()).max_by_key(|&v| a[v].len()).unwrap();
+    let n = a[v].len();
+    assert_eq!(t.sum(v, 0..n), *a[v].iter().max().unwrap());
+}
+
+#[test]
+fn map() {
+    use std::collections::BTreeMap;
+    let mut t = TreapMap::<i32, i64, Add>::new();
+    let mut a = BTreeMap::new();
+    let rng = Rng::new();
+    for _ in 0..3000 {
+        let k = (rng.gen() % 200) as i32 - 100;
+        let v = (rng.gen() % 1000) as i64;
+        match rng.gen() % 3 {
+            0 => assert_eq!(t.insert(k, v), a.insert(k, v)),
+            1 => assert_eq!(t.remove(&k), a.remove(&k)),
+            _ => {
+                let r = k + (rng.gen() % 50) as i32;
+                assert_eq!(t.sum(k..r), a.range(k..r).map(|(_, v)| v).sum::<i64>());
+            }
+        }
+        assert_eq!(t.len(), a.len());
+        assert_eq!(t.get(&k), a.get(&k).copied());
+        assert_eq!(t.rank(&k), a.range(..k).count());
+        assert_eq!(
+            t.lower_bound(&k),
+            a.range(k..).next().map(|(&k, &v)| (k, v))
+        );
+        let n = rng.gen() as usize % (a.len() + 1);
+        assert_eq!(t.kth(n), a.iter().nth(n).map(|(&k, &v)| (k, v)));
+    }
+    assert_eq!(t.all(), a.values().sum::<i64>());
+
+    let (l, r) = t.split_by_key(&0);
+    assert_eq!(l.len(), a.range(..0).count());
+    assert_eq!(r.kth(0), a.range(0..).next().map(|(&k, &v)| (k, v)));
+    let t = l.merge(r);
+    let mut b = vec![];
+    t.walk(|k, v| b.push((k, v)));
+    assert_eq!(b, a.into_iter().collect::<Vec<_>>());
+}
+
+fn lazy_to_vec<V: Monoid<Add> + Copy>(t: &mut TreapLazy<V, Add>) -> Vec<V> {
+    let mut res = vec![];
+    t.walk(|_, x| res.push(x));
+    res
+}
+
+#[test]
+fn lazy_cut_paste() {
+    let rng = Rng::new();
+    let mut a: Vec<i64> = (0..100).collect();
+    let mut t = TreapLazy::<i64, Add>::from_slice(&a);
+    assert_eq!(t.len(), 100);
+    for _ in 0..500 {
+        let n = a.len();
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        let p = rng.gen() as usize % (n - (r - l) + 1);
+        t.cut_paste(l..r, p);
+        let b: Vec<_> = a.drain(l..r).collect();
+        a.splice(p..p, b);
+        let k = rng.gen() as usize % n;
+        assert_eq!(a[t.find_index(k)], k as i64);
+    }
+    assert_eq!(lazy_to_vec(&mut t), a);
+}
+
+#[test]
+fn lazy_split_append() {
+    let rng = Rng::new();
+    for _ in 0..20 {
+        let n = rng.gen() as usize % 50;
+        let a: Vec<i64> = (0..n as i64).collect();
+        let mut t = TreapLazy::<i64, Add>::from_slice(&a);
+        let at = rng.gen() as usize % (n + 1);
+        let (mut r, moved) = t.split_off(at);
+        assert_eq!(moved, (at..n).collect::<Vec<_>>());
+        assert_eq!(lazy_to_vec(&mut t), a[..at].to_vec());
+        assert_eq!(lazy_to_vec(&mut r), a[at..].to_vec());
+        assert_eq!(r.sum(0..n - at), a[at..].iter().sum::<i64>());
+        for i in 0..n - at {
+            assert_eq!(r.find_index(i), i);
+        }
+        r.insert_at(0, -1);
+        r.append(t);
+        let mut b = vec![-1];
+        b.extend_from_slice(&a[at..]);
+        b.extend_from_slice(&a[..at]);
+        assert_eq!(lazy_to_vec(&mut r), b);
+        assert_eq!(r.len(), n + 1);
+        for i in 0..at {
+            assert_eq!(r.find_index(n - at + 1 + i), n - at + 1 + i);
+        }
+    }
+}
+
+#[test]
+fn lazy_split_ids() {
+    let mut t = TreapLazy::<i64, Add>::from_slice(&[0, 1, 2, 3]);
+    t.cut_paste(1..2, 3);
+    assert_eq!(lazy_to_vec(&mut t), [0, 2, 3, 1]);
+    let (r, moved) = t.split_off(1);
+    // kept index 0 stays, moved [2, 3, 1] become [1, 2, 0]
+    assert_eq!(t.find_index(0), 0);
+    assert_eq!(moved, [1, 2, 3]);
+    assert_eq!(
+        (0..3).map(|j| r.find_index(j)).collect::<Vec<_>>(),
+        [2, 0, 1]
+    );
+    // kept indexes still valid after more edits
+    t.insert_at(0, 9);
+    assert_eq!(t.find_index(0), 1);
+}
+
+#[test]
+fn persistent_doubling() {
+    let mut t = TreapPersistent::<i64, Add>::from_slice(&[1, 2, 3, 4, 5]);
+    let mut v = 0;
+    for _ in 0..30 {
+        v = t.concat(v, v);
+    }
+    let n = 5 << 30;
+    assert_eq!(t.versions[v].len(), n);
+    let pre = |x: usize| (x / 5 * 15 + (x % 5) * (x % 5 + 1) / 2) as i64;
+    let rng = Rng::new();
+    for _ in 0..1000 {
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        assert_eq!(t.sum(v, l..r), pre(r) - pre(l));
+    }
+}
+
+#[test]
+fn persistent_cut_paste() {
+    let rng = Rng::new();
+    let a: Vec<i64> = (0..60).collect();
+    let mut t = TreapPersistent::<i64, Add>::from_slice(&a);
+    let mut a = vec![a];
+    for _ in 0..300 {
+        let v = rng.gen() as usize % a.len();
+        let mut b = a[v].clone();
+        let n = b.len();
+        let mut l = rng.gen() as usize % (n + 1);
+        let mut r = rng.gen() as usize % (n + 1);
+        if l > r {
+            std::mem::swap(&mut l, &mut r);
+        }
+        match rng.gen() % 3 {
+            0 => {
+                let p = rng.gen() as usize % (n - (r - l) + 1);
+                assert_eq!(t.cut_paste(v, l..r, p), a.len());
+                let c: Vec<_> = b.drain(l..r).collect();
+                b.splice(p..p, c);
+                a.push(b);
+            }
+            1 => {
+                assert_eq!(t.split_at(v, l), (a.len(), a.len() + 1));
+                let c = b.split_off(l);
+                a.push(b);
+                a.push(c);
+            }
+            _ => {
+                let u = rng.gen() as usize % a.len();
+                if n + a[u].len() > 1000 {
+                    continue;
+                }
+                assert_eq!(t.concat(v, u), a.len());
+                b.extend_from_slice(&a[u]);
+                a.push(b);
+            }
+        }
+    }
+    for (v, b) in a.iter().enumerate() {
+        assert_eq!(*b, to_vec(&mut t.versions[v]));
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::VecDeque;
+use std::ops::Index;
+
+/// Input edge `(u, v)` for unweighted, `(u, v, w)` for weighted.
+pub trait IntoEdge<W> {
+    fn into_edge(self) -> (usize, usize, W);
+}
+impl IntoEdge<()> for (usize, usize) {
+    #[inline]
+    fn into_edge(self) -> (usize, usize, ()) {
+        (self.0, self.1, ())
+    }
+}
+impl<W> IntoEdge<W> for (usize, usize, W) {
+    #[inline]
+    fn into_edge(self) -> (usize, usize, W) {
+        self
+    }
+}
+
+/// Outgoing edge, `id` is index in input edge list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edge<W> {
+    pub to: usize,
+    pub w: W,
+    pub id: usize,
+}
+
+/// Compressed sparse row adjacency, `g[u]` are edges out of `u`.
+///
+/// Undirected edge stored in both directions with same `id`, self-loop twice.
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::Csr;
+///
+/// let g = Csr::undirected(3, &[(0, 1), (1, 2)]);
+/// assert_eq!(g.bfs(1).collect::<Vec<_>>(), vec![1, 0, 2]);
+/// let g = Csr::directed(3, &[(0, 1, 5), (0, 2, 7)]);
+/// assert_eq!(g[0][1].w, 7);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Csr<W = ()> {
+    start: Vec<usize>,
+    e: Vec<Edge<W>>,
+    m: usize,
+    directed: bool,
+}
+
+impl<W: Copy> Csr<W> {
+    pub fn directed<E: IntoEdge<W> + Copy>(n: usize, edges: &[E]) -> Self {
+        Self::build(n, edges, true)
+    }
+    pub fn undirected<E: IntoEdge<W> + Copy>(n: usize, edges: &[E]) -> Self {
+        Self::build(n, edges, false)
+    }
+    fn build<E: IntoEdge<W> + Copy>(n: usize, edges: &[E], directed: bool) -> Self {
+        let edges: Vec<_> = edges.iter().map(|e| e.into_edge()).collect();
+        let mut start = vec![0; n + 1];
+        for &(u, v, _) in &edges {
+            start[u + 1] += 1;
+            if !directed {
+                start[v + 1] += 1;
+            }
+        }
+        for i in 0..n {
+            start[i + 1] += start[i];
+        }
+        // slot -> (id, is reversed copy)
+        let mut pos = start.clone();
+        let mut slot = vec![(0, false); start[n]];
+        for (id, &(u, v, _)) in edges.iter().enumerate() {
+            slot[pos[u]] = (id, false);
+            pos[u] += 1;
+            if !directed {
+                slot[pos[v]] = (id, true);
+                pos[v] += 1;
+            }
+        }
+        let e = slot
+            .into_iter()
+            .map(|(id, rev)| {
+                let (u, v, w) = edges[id];
+                Edge {
+                    to: if rev { u } else { v },
+                    w,
+                    id,
+                }
+            })
+            .collect();
+        Self {
+            start,
+            e,
+            m: edges.len(),
+            directed,
+        }
+    }
+    /// Number of vertices.
+    pub fn len(&self) -> usize {
+        self.start.len() - 1
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Number of input edges.
+    pub fn edges(&self) -> usize {
+        self.m
+    }
+    pub fn adj(&self, u: usize) -> &[Edge<W>] {
+        &self.e[self.start[u]..self.start[u + 1]]
+    }
+    /// Reversed direction, `id` kept, require directed.
+    pub fn reversed(&self) -> Self {
+        debug_assert!(self.directed, "not directed");
+        let mut edges = vec![None; self.m];
+        for u in 0..self.len() {
+            for e in self.adj(u) {
+                edges[e.id] = Some((e.to, u, e.w));
+            }
+        }
+        let edges: Vec<_> = edges.into_iter().map(Option::unwrap).collect();
+        Self::directed(self.len(), &edges)
+    }
+
+    /// Vertices reachable from `s`, in bfs order.
+    pub fn bfs(&self, s: usize) -> Bfs<'_, W> {
+        let mut vis = vec![false; self.len()];
+        vis[s] = true;
+        Bfs {
+            g: self,
+            q: vec![s].into(),
+            vis,
+        }
+    }
+    /// Vertices reachable from `s`, in dfs pre-order.
+    pub fn dfs_pre(&self, s: usize) -> Dfs<'_, W> {
+        Dfs::new(self, s, false)
+    }
+    /// Vertices reachable from `s`, in dfs post-order.
+    pub fn dfs_post(&self, s: usize) -> Dfs<'_, W> {
+        Dfs::new(self, s, true)
+    }
+    /// Connected components ordered by min member, each in bfs order.
+    ///
+    /// Weakly connected components if directed, edges walked both ways.
+    pub fn components(&self) -> Components<'_, W> {
+        Components {
+            g: self,
+            rev: if self.directed {
+                Some(self.reversed())
+            } else {
+                None
+            },
+            s: 0,
+            vis: vec![false; self.len()],
+        }
+    }
+}
+
+impl<W: Copy> Index<usize> for Csr<W> {
+    type Output = [Edge<W>];
+    fn index(&self, u: usize) -> &Self::Output {
+        self.adj(u)
+    }
+}
+
+pub struct Bfs<'a, W> {
+    g: &'a Csr<W>,
+    q: VecDeque<usize>,
+    vis: Vec<bool>,
+}
+impl<'a, W: Copy> Iterator for Bfs<'a, W> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let u = self.q.pop_front()?;
+        for e in self.g.adj(u) {
+            if !self.vis[e.to] {
+                self.vis[e.to] = true;
+                self.q.push_back(e.to);
+            }
+        }
+        Some(u)
+    }
+}
+
+/// Iterative dfs, stack of `(u, next edge)`.
+pub struct Dfs<'a, W> {
+    g: &'a Csr<W>,
+    st: Vec<(usize, usize)>,
+    vis: Vec<bool>,
+    post: bool,
+    /// pre-order pending to yield.
+    pre: Option<usize>,
+}
+impl<'a, W: Copy> Dfs<'a, W> {
+    fn new(g: &'a Csr<W>, s: usize, post: bool) -> Self {
+        let mut vis = vec![false; g.len()];
+        vis[s] = true;
+        Self {
+            g,
+            st: vec![(s, 0)],
+            vis,
+            post,
+            pre: Some(s),
+        }
+    }
+}
+impl<'a, W: Copy> Iterator for Dfs<'a, W> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        if !self.post {
+            if let Some(u) = self.pre.take() {
+                return Some(u);
+            }
+        }
+        while let Some(&mut (u, ref mut i)) = self.st.last_mut() {
+            let adj = self.g.adj(u);
+            if *i == adj.len() {
+                self.st.pop();
+                if self.post {
+                    return Some(u);
+                }
+                continue;
+            }
+            let v = adj[*i].to;
+            *i += 1;
+            if !self.vis[v] {
+                self.vis[v] = true;
+                self.st.push((v, 0));
+                if !self.post {
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct Components<'a, W> {
+    g: &'a Csr<W>,
+    rev: Option<Csr<W>>,
+    s: usize,
+    vis: Vec<bool>,
+}
+impl<'a, W: Copy> Iterator for Components<'a, W> {
+    type Item = Vec<usize>;
+    fn next(&mut self) -> Option<Vec<usize>> {
+        while self.s < self.g.len() && self.vis[self.s] {
+            self.s += 1;
+        }
+        if self.s == self.g.len() {
+            return None;
+        }
+        let (g, rev, vis) = (self.g, &self.rev, &mut self.vis);
+        let mut res = vec![self.s];
+        vis[self.s] = true;
+        let mut i = 0;
+        while i < res.len() {
+            let u = res[i];
+            for e in g.adj(u).iter().chain(rev.iter().flat_map(|r| r.adj(u))) {
+                if !vis[e.to] {
+                    vis[e.to] = true;
+                    res.push(e.to);
+                }
+            }
+            i += 1;
+        }
+        Some(res)
+    }
+}
+
+#[cfg(test)]
+mod tests;
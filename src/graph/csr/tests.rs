@@ -0,0 +1,119 @@
+use super::*;
+use crate::core::Rng;
+use crate::ds::dsu::Dsu;
+use crate::graph::fixture::random_edges;
+
+#[test]
+fn layout() {
+    let g = Csr::undirected(4, &[(0, 1, 3), (1, 2, 4), (2, 2, 5)]);
+    assert_eq!(g.len(), 4);
+    assert_eq!(g.edges(), 3);
+    assert_eq!(
+        g[1].iter().map(|e| (e.to, e.w, e.id)).collect::<Vec<_>>(),
+        vec![(0, 3, 0), (2, 4, 1)]
+    );
+    // self-loop twice
+    assert_eq!(g[2].iter().filter(|e| e.to == 2).count(), 2);
+    assert!(g[3].is_empty());
+    let g = Csr::directed(3, &[(0, 1), (0, 2), (2, 1)]);
+    let r = g.reversed();
+    assert_eq!(
+        r[1].iter().map(|e| (e.to, e.id)).collect::<Vec<_>>(),
+        vec![(0, 0), (2, 2)]
+    );
+    assert!(r[0].is_empty());
+}
+
+#[test]
+fn orders() {
+    // 0 - 1 - 3
+    //   \ 2 - 4
+    let g = Csr::undirected(6, &[(0, 1), (0, 2), (1, 3), (2, 4)]);
+    assert_eq!(g.bfs(0).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(g.dfs_pre(0).collect::<Vec<_>>(), vec![0, 1, 3, 2, 4]);
+    assert_eq!(g.dfs_post(0).collect::<Vec<_>>(), vec![3, 1, 4, 2, 0]);
+    assert_eq!(g.dfs_post(5).collect::<Vec<_>>(), vec![5]);
+}
+
+#[test]
+fn components_vs_dsu() {
+    let rng = Rng::new();
+    for _ in 0..50 {
+        let n = 1 + rng.gen() as usize % 30;
+        let m = rng.gen() as usize % 30;
+        let edges = random_edges(&rng, n, m);
+        let g = Csr::undirected(n, &edges);
+        let mut d = Dsu::new(n);
+        for &(u, v) in &edges {
+            d.join(u, v);
+        }
+        let comps: Vec<_> = g
+            .components()
+            .map(|mut c| {
+                c.sort_unstable();
+                c
+            })
+            .collect();
+        assert_eq!(comps, d.groups());
+        let weak: Vec<_> = Csr::directed(n, &edges)
+            .components()
+            .map(|mut c| {
+                c.sort_unstable();
+                c
+            })
+            .collect();
+        assert_eq!(weak, comps);
+        // every traversal from a vertex covers its component
+        for s in 0..n {
+            for order in [
+                g.bfs(s).collect::<Vec<_>>(),
+                g.dfs_pre(s).collect(),
+                g.dfs_post(s).collect(),
+            ]
+            .iter_mut()
+            {
+                assert_eq!(order.len(), d.size(s));
+                assert!(order.iter().all(|&v| d.check(s, v)));
+                order.sort_unstable();
+                order.dedup();
+                assert_eq!(order.len(), d.size(s));
+            }
+        }
+    }
+}
+
+#[test]
+fn components_directed() {
+    let g = Csr::directed(2, &[(1, 0)]);
+    assert_eq!(g.components().collect::<Vec<_>>(), vec![vec![0, 1]]);
+    let g = Csr::directed(4, &[(3, 1), (2, 1), (0, 2)]);
+    assert_eq!(g.components().collect::<Vec<_>>(), vec![vec![0, 2, 1, 3]]);
+}
+
+#[test]
+fn post_order_on_dag() {
+    let rng = Rng::new();
+    let n = 30;
+    // edges go forward, so post-order puts successors first
+    let edges: Vec<_> = random_edges(&rng, n, 60)
+        .into_iter()
+        .filter(|&(u, v)| u < v)
+        .collect();
+    let g = Csr::directed(n, &edges);
+    let mut pos = vec![n; n];
+    for (i, u) in g.dfs_post(0).enumerate() {
+        pos[u] = i;
+    }
+    for &(u, v) in &edges {
+        if pos[u] < n {
+            assert!(pos[v] < pos[u]);
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not directed")]
+fn reversed_undirected() {
+    Csr::undirected(2, &[(0, 1)]).reversed();
+}
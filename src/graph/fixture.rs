@@ -0,0 +1,38 @@
+//! Shared random inputs for graph tests.
+use super::Csr;
+use crate::core::Rng;
+
+/// `m` random edges on `n` vertices, self-loops and parallel edges allowed.
+pub fn random_edges(rng: &Rng, n: usize, m: usize) -> Vec<(usize, usize)> {
+    (0..m)
+        .map(|_| (rng.gen() as usize % n, rng.gen() as usize % n))
+        .collect()
+}
+
+/// Random tree on `n` vertices, parent of `v` drawn from `0..v`.
+pub fn random_tree(rng: &Rng, n: usize) -> Csr {
+    let edges: Vec<_> = (1..n).map(|v| (rng.gen() as usize % v, v)).collect();
+    Csr::undirected(n, &edges)
+}
+
+/// Vertices on path `u` to `v`, by walking up `parent`.
+pub fn brute_path(
+    parent: &[Option<usize>],
+    depth: &[usize],
+    mut u: usize,
+    mut v: usize,
+) -> Vec<usize> {
+    let (mut l, mut r) = (vec![], vec![]);
+    while u != v {
+        if depth[u] >= depth[v] {
+            l.push(u);
+            u = parent[u].unwrap();
+        } else {
+            r.push(v);
+            v = parent[v].unwrap();
+        }
+    }
+    l.push(u);
+    l.extend(r.into_iter().rev());
+    l
+}
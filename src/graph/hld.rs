@@ -0,0 +1,213 @@
+use super::tree::dfs_tree;
+use super::Csr;
+use crate::algebra::{Affine, Monoid, Rev};
+use crate::ds::seg::{SegLazy, SegTree};
+use std::ops::Range;
+
+/// Heavy-light decomposition.
+///
+/// Each heavy path, and each subtree, occupies a contiguous range of `pos`.
+///
+/// # Time complexity
+///
+/// Build *O*(*n*), path of *O*(log *n*) ranges.
+#[derive(Clone, Debug)]
+pub struct Hld {
+    pub parent: Vec<Option<usize>>,
+    pub depth: Vec<usize>,
+    pub size: Vec<usize>,
+    /// Top vertex of heavy path.
+    pub head: Vec<usize>,
+    /// `order[pos[v]] = v`.
+    pub pos: Vec<usize>,
+    pub order: Vec<usize>,
+}
+
+impl Hld {
+    /// `g` must be a tree, undirected.
+    pub fn new<W: Copy>(g: &Csr<W>, root: usize) -> Self {
+        let n = g.len();
+        let (parent, depth, pre) = dfs_tree(g, root);
+        let mut size = vec![1; n];
+        let mut heavy = vec![None; n];
+        for &v in pre.iter().rev() {
+            if let Some(p) = parent[v] {
+                size[p] += size[v];
+                if !matches!(heavy[p], Some(h) if size[h] >= size[v]) {
+                    heavy[p] = Some(v);
+                }
+            }
+        }
+        // dfs again, heavy child popped right after parent
+        let mut head: Vec<_> = (0..n).collect();
+        let mut pos = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        let mut st = vec![root];
+        while let Some(u) = st.pop() {
+            pos[u] = order.len();
+            order.push(u);
+            for e in g.adj(u) {
+                if parent[e.to] == Some(u) && Some(e.to) != heavy[u] {
+                    st.push(e.to);
+                }
+            }
+            if let Some(h) = heavy[u] {
+                head[h] = head[u];
+                st.push(h);
+            }
+        }
+        Self {
+            parent,
+            depth,
+            size,
+            head,
+            pos,
+            order,
+        }
+    }
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]].unwrap();
+        }
+        if self.depth[u] < self.depth[v] {
+            u
+        } else {
+            v
+        }
+    }
+    /// Ranges of `pos` along path `u` to `v` in order, `true` if walked against `pos`, i.e. upward.
+    pub fn path(&self, mut u: usize, mut v: usize) -> Vec<(Range<usize>, bool)> {
+        let (mut up, mut down) = (vec![], vec![]);
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] >= self.depth[self.head[v]] {
+                up.push((self.pos[self.head[u]]..self.pos[u] + 1, true));
+                u = self.parent[self.head[u]].unwrap();
+            } else {
+                down.push((self.pos[self.head[v]]..self.pos[v] + 1, false));
+                v = self.parent[self.head[v]].unwrap();
+            }
+        }
+        if self.depth[u] >= self.depth[v] {
+            up.push((self.pos[v]..self.pos[u] + 1, true));
+        } else {
+            down.push((self.pos[u]..self.pos[v] + 1, false));
+        }
+        up.extend(down.into_iter().rev());
+        up
+    }
+    pub fn subtree(&self, v: usize) -> Range<usize> {
+        self.pos[v]..self.pos[v] + self.size[v]
+    }
+    /// `a` by vertex, into `pos` order.
+    fn arrange<T: Copy>(&self, a: &[T]) -> Vec<T> {
+        self.order.iter().map(|&v| a[v]).collect()
+    }
+}
+
+/// Vertex values on `SegTree`, path in order, so monoid needn't be commutative.
+///
+/// Keep a reversed copy for upward ranges.
+pub struct HldSeg<T, M> {
+    pub hld: Hld,
+    fwd: SegTree<T, M>,
+    rev: SegTree<T, Rev<M>>,
+}
+
+impl<T, M> HldSeg<T, M>
+where
+    T: Monoid<M> + Copy,
+{
+    /// `a[v]` for vertex `v`.
+    pub fn new(hld: Hld, a: &[T]) -> Self {
+        let b = hld.arrange(a);
+        Self {
+            fwd: SegTree::from(&b),
+            rev: SegTree::<T, Rev<M>>::from(&b),
+            hld,
+        }
+    }
+    pub fn set(&mut self, v: usize, x: T) {
+        let i = self.hld.pos[v];
+        self.fwd.set(i, x);
+        self.rev.set(i, x);
+    }
+    pub fn get(&self, v: usize) -> T {
+        let i = self.hld.pos[v];
+        self.fwd.sum(i..i + 1)
+    }
+    /// Sum along path `u` to `v`, both inclusive.
+    pub fn path_sum(&self, u: usize, v: usize) -> T {
+        self.hld.path(u, v).into_iter().fold(T::ID, |s, (r, up)| {
+            let x = if up { self.rev.sum(r) } else { self.fwd.sum(r) };
+            T::binop(s, x)
+        })
+    }
+    /// Sum of subtree, in `pos` order.
+    pub fn subtree_sum(&self, v: usize) -> T {
+        self.fwd.sum(self.hld.subtree(v))
+    }
+}
+
+/// Vertex values on `SegLazy`, path in order, so monoid needn't be commutative.
+///
+/// Keep a reversed copy for upward ranges.
+pub struct HldLazy<T, U, M, D, F> {
+    pub hld: Hld,
+    fwd: SegLazy<T, U, M, D, F>,
+    rev: SegLazy<T, U, Rev<M>, D, F>,
+}
+
+impl<T, U, M, D, F> HldLazy<T, U, M, D, F>
+where
+    T: Monoid<M> + Copy + Affine<U, F>,
+    U: Monoid<D> + Copy + PartialEq + Eq,
+{
+    /// `a[v]` for vertex `v`.
+    pub fn new(hld: Hld, a: &[T]) -> Self {
+        let b = hld.arrange(a);
+        Self {
+            fwd: SegLazy::from(&b),
+            rev: SegLazy::<T, U, Rev<M>, D, F>::from(&b),
+            hld,
+        }
+    }
+    pub fn set(&mut self, v: usize, x: T) {
+        let i = self.hld.pos[v];
+        self.fwd.set(i, x);
+        self.rev.set(i, x);
+    }
+    pub fn get(&mut self, v: usize) -> T {
+        self.fwd.get(self.hld.pos[v])
+    }
+    /// Sum along path `u` to `v`, both inclusive.
+    pub fn path_sum(&mut self, u: usize, v: usize) -> T {
+        let mut s = T::ID;
+        for (r, up) in self.hld.path(u, v) {
+            let x = if up { self.rev.sum(r) } else { self.fwd.sum(r) };
+            s = T::binop(s, x);
+        }
+        s
+    }
+    /// Act `x` on path `u` to `v`.
+    pub fn path_apply(&mut self, u: usize, v: usize, x: U) {
+        for (r, _) in self.hld.path(u, v) {
+            self.fwd.add(r.clone(), x);
+            self.rev.add(r, x);
+        }
+    }
+    /// Sum of subtree, in `pos` order.
+    pub fn subtree_sum(&mut self, v: usize) -> T {
+        self.fwd.sum(self.hld.subtree(v))
+    }
+    pub fn subtree_apply(&mut self, v: usize, x: U) {
+        let r = self.hld.subtree(v);
+        self.fwd.add(r.clone(), x);
+        self.rev.add(r, x);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,165 @@
+use super::*;
+use crate::algebra::*;
+use crate::core::Rng;
+use crate::graph::fixture::{brute_path, random_tree};
+
+const P: i64 = 998_244_353;
+// x -> ax+b mod p, composed along path.
+monoid_new!(impl Compose for (i64, i64), (1, 0), |f, g| (f.0 * g.0 % P, (f.1 * g.0 + g.1) % P));
+// (sum, len) with range add.
+monoid_new!(impl SumLen for (i64, i64), (0, 0), |x, y| (x.0 + y.0, x.1 + y.1));
+affine_new!(impl AddAll<i64> for (i64, i64), |x, u| (x.0 + u * x.1, x.1));
+
+fn compose(a: &[(i64, i64)], vs: impl Iterator<Item = usize>) -> (i64, i64) {
+    vs.fold((1, 0), |f, v| {
+        <(i64, i64) as Monoid<Compose>>::binop(f, a[v])
+    })
+}
+
+fn random_affine(rng: &Rng) -> (i64, i64) {
+    ((rng.gen() % P as u64) as i64, (rng.gen() % P as u64) as i64)
+}
+
+#[test]
+fn decomposition() {
+    let rng = Rng::new();
+    for _ in 0..30 {
+        let n = 1 + rng.gen() as usize % 50;
+        let g = random_tree(&rng, n);
+        let root = rng.gen() as usize % n;
+        let h = Hld::new(&g, root);
+        for v in 0..n {
+            assert_eq!(h.order[h.pos[v]], v);
+            let mut sub: Vec<_> = h.order[h.subtree(v)].to_vec();
+            sub.sort_unstable();
+            let expect: Vec<_> = (0..n)
+                .filter(|&x| {
+                    let mut x = Some(x);
+                    while x.is_some() && x != Some(v) {
+                        x = h.parent[x.unwrap()];
+                    }
+                    x.is_some()
+                })
+                .collect();
+            assert_eq!(sub, expect);
+        }
+        for u in 0..n {
+            for v in 0..n {
+                let p = brute_path(&h.parent, &h.depth, u, v);
+                let got: Vec<_> = h
+                    .path(u, v)
+                    .into_iter()
+                    .flat_map(|(r, up)| {
+                        let mut x: Vec<_> = h.order[r].to_vec();
+                        if up {
+                            x.reverse();
+                        }
+                        x
+                    })
+                    .collect();
+                assert_eq!(got, p);
+                assert_eq!(h.lca(u, v), *p.iter().min_by_key(|&&x| h.depth[x]).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn seg_non_commutative() {
+    let rng = Rng::new();
+    for _ in 0..10 {
+        let n = 1 + rng.gen() as usize % 60;
+        let g = random_tree(&rng, n);
+        let root = rng.gen() as usize % n;
+        let mut a: Vec<_> = (0..n).map(|_| random_affine(&rng)).collect();
+        let mut s = HldSeg::<_, Compose>::new(Hld::new(&g, root), &a);
+        for _ in 0..200 {
+            let u = rng.gen() as usize % n;
+            let v = rng.gen() as usize % n;
+            if rng.gen() % 3 == 0 {
+                a[u] = random_affine(&rng);
+                s.set(u, a[u]);
+            } else {
+                let p = brute_path(&s.hld.parent, &s.hld.depth, u, v);
+                assert_eq!(s.path_sum(u, v), compose(&a, p.into_iter()));
+                assert_eq!(s.get(u), a[u]);
+                let sub = s.hld.subtree(v);
+                assert_eq!(
+                    s.subtree_sum(v),
+                    compose(&a, s.hld.order[sub].iter().copied())
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn lazy_path_add() {
+    let rng = Rng::new();
+    for _ in 0..10 {
+        let n = 1 + rng.gen() as usize % 60;
+        let g = random_tree(&rng, n);
+        let root = rng.gen() as usize % n;
+        let mut a: Vec<i64> = (0..n).map(|_| (rng.gen() % 100) as i64).collect();
+        let b: Vec<_> = a.iter().map(|&x| (x, 1)).collect();
+        let mut s = HldLazy::<_, _, SumLen, Add, AddAll>::new(Hld::new(&g, root), &b);
+        for _ in 0..200 {
+            let u = rng.gen() as usize % n;
+            let v = rng.gen() as usize % n;
+            let x = (rng.gen() % 100) as i64;
+            match rng.gen() % 4 {
+                0 => {
+                    for w in brute_path(&s.hld.parent, &s.hld.depth, u, v) {
+                        a[w] += x;
+                    }
+                    s.path_apply(u, v, x);
+                }
+                1 => {
+                    for &w in &s.hld.order[s.hld.subtree(v)] {
+                        a[w] += x;
+                    }
+                    s.subtree_apply(v, x);
+                }
+                2 => {
+                    let p = brute_path(&s.hld.parent, &s.hld.depth, u, v);
+                    let expect = p.iter().map(|&w| a[w]).sum::<i64>();
+                    assert_eq!(s.path_sum(u, v), (expect, p.len() as i64));
+                }
+                _ => {
+                    let sub = s.hld.subtree(v);
+                    let expect = s.hld.order[sub.clone()].iter().map(|&w| a[w]).sum::<i64>();
+                    assert_eq!(s.subtree_sum(v), (expect, sub.len() as i64));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn lazy_non_commutative() {
+    let rng = Rng::new();
+    let n = 50;
+    let g = random_tree(&rng, n);
+    let root = rng.gen() as usize % n;
+    let mut a: Vec<_> = (0..n).map(|_| random_affine(&rng)).collect();
+    let mut s = HldLazy::<_, (), Compose, (), ()>::new(Hld::new(&g, root), &a);
+    for _ in 0..300 {
+        let u = rng.gen() as usize % n;
+        let v = rng.gen() as usize % n;
+        if rng.gen() % 2 == 0 {
+            a[u] = random_affine(&rng);
+            s.set(u, a[u]);
+        } else {
+            let p = brute_path(&s.hld.parent, &s.hld.depth, u, v);
+            assert_eq!(s.path_sum(u, v), compose(&a, p.into_iter()));
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not a tree")]
+fn cycle() {
+    let g = Csr::undirected(3, &[(0, 1), (1, 2), (2, 0)]);
+    Hld::new(&g, 0);
+}
@@ -0,0 +1,167 @@
+use super::Csr;
+
+/// Lowlink of undirected graph, with bridges, articulation points and biconnected blocks.
+///
+/// Parent edge skipped by edge id, so multi-edges are fine, self-loops are ignored except
+/// each forms a block of its own.
+///
+/// # Time complexity
+///
+/// *O*(*n* + *m*).
+#[derive(Clone, Debug)]
+pub struct LowLink {
+    pub tin: Vec<usize>,
+    pub low: Vec<usize>,
+    /// `bridge[e]` by edge id.
+    pub bridge: Vec<bool>,
+    /// `cut[v]`, i.e. articulation point.
+    pub cut: Vec<bool>,
+    /// Biconnected block of each edge id.
+    pub block: Vec<usize>,
+    pub blocks: usize,
+}
+
+impl LowLink {
+    pub fn new<W: Copy>(g: &Csr<W>) -> Self {
+        const NIL: usize = usize::MAX;
+        let n = g.len();
+        let mut tin = vec![NIL; n];
+        let mut low = vec![NIL; n];
+        let mut bridge = vec![false; g.edges()];
+        let mut cut = vec![false; n];
+        let mut block = vec![NIL; g.edges()];
+        let mut blocks = 0;
+        let mut timer = 0;
+        // edges of current blocks
+        let mut es = vec![];
+        for s in 0..n {
+            if tin[s] != NIL {
+                continue;
+            }
+            tin[s] = timer;
+            low[s] = timer;
+            timer += 1;
+            let mut children = 0;
+            // (u, parent edge, next)
+            let mut call = vec![(s, NIL, 0)];
+            while let Some(&mut (u, pe, ref mut i)) = call.last_mut() {
+                if let Some(e) = g.adj(u).get(*i) {
+                    *i += 1;
+                    let v = e.to;
+                    if e.id == pe {
+                        continue;
+                    }
+                    if v == u {
+                        if block[e.id] == NIL {
+                            block[e.id] = blocks;
+                            blocks += 1;
+                        }
+                    } else if tin[v] == NIL {
+                        tin[v] = timer;
+                        low[v] = timer;
+                        timer += 1;
+                        es.push(e.id);
+                        call.push((v, e.id, 0));
+                        if u == s {
+                            children += 1;
+                        }
+                    } else if tin[v] < tin[u] {
+                        low[u] = low[u].min(tin[v]);
+                        es.push(e.id);
+                    }
+                    continue;
+                }
+                call.pop();
+                if let Some(&(p, _, _)) = call.last() {
+                    low[p] = low[p].min(low[u]);
+                    if low[u] >= tin[p] {
+                        cut[p] |= p != s;
+                        loop {
+                            let x = es.pop().unwrap();
+                            block[x] = blocks;
+                            if x == pe {
+                                break;
+                            }
+                        }
+                        blocks += 1;
+                    }
+                    bridge[pe] = low[u] > tin[p];
+                }
+            }
+            cut[s] = children >= 2;
+        }
+        Self {
+            tin,
+            low,
+            bridge,
+            cut,
+            block,
+            blocks,
+        }
+    }
+    /// Edge ids.
+    pub fn bridges(&self) -> Vec<usize> {
+        (0..self.bridge.len()).filter(|&e| self.bridge[e]).collect()
+    }
+    pub fn articulation_points(&self) -> Vec<usize> {
+        (0..self.cut.len()).filter(|&v| self.cut[v]).collect()
+    }
+    /// 2-edge-connected components, ret: `(count, id)`, `id[v]` of component.
+    ///
+    /// Same partition as `Dsu` joined by all non-bridge edges.
+    pub fn two_edge_components<W: Copy>(&self, g: &Csr<W>) -> (usize, Vec<usize>) {
+        let n = g.len();
+        let mut id = vec![n; n];
+        let mut count = 0;
+        for s in 0..n {
+            if id[s] != n {
+                continue;
+            }
+            id[s] = count;
+            let mut st = vec![s];
+            while let Some(u) = st.pop() {
+                for e in g.adj(u) {
+                    if !self.bridge[e.id] && id[e.to] == n {
+                        id[e.to] = count;
+                        st.push(e.to);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (count, id)
+    }
+    /// Vertices of each block.
+    pub fn block_vertices<W: Copy>(&self, g: &Csr<W>) -> Vec<Vec<usize>> {
+        let mut res = vec![vec![]; self.blocks];
+        for u in 0..g.len() {
+            for e in g.adj(u) {
+                let b = &mut res[self.block[e.id]];
+                if b.last() != Some(&u) {
+                    b.push(u);
+                }
+            }
+        }
+        for b in res.iter_mut() {
+            b.sort_unstable();
+            b.dedup();
+        }
+        res
+    }
+    /// Block-cut tree(forest), vertex `v` as itself, block `b` as `n + b`.
+    ///
+    /// Isolated vertices stay isolated.
+    pub fn block_cut_tree<W: Copy>(&self, g: &Csr<W>) -> Csr {
+        let n = g.len();
+        let mut edges = vec![];
+        for (b, vs) in self.block_vertices(g).into_iter().enumerate() {
+            for v in vs {
+                edges.push((v, n + b));
+            }
+        }
+        Csr::undirected(n + self.blocks, &edges)
+    }
+}
+
+#[cfg(test)]
+mod tests;
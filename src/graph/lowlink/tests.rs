@@ -0,0 +1,86 @@
+use super::*;
+use crate::core::Rng;
+use crate::ds::dsu::Dsu;
+use crate::graph::fixture::random_edges;
+
+fn components(n: usize, edges: impl Iterator<Item = (usize, usize)>) -> usize {
+    let mut d = Dsu::new(n);
+    for (u, v) in edges {
+        d.join(u, v);
+    }
+    d.components()
+}
+
+#[test]
+fn random() {
+    let rng = Rng::new();
+    for _ in 0..200 {
+        let n = 1 + rng.gen() as usize % 10;
+        let m = rng.gen() as usize % 15;
+        let edges = random_edges(&rng, n, m);
+        let g = Csr::undirected(n, &edges);
+        let l = LowLink::new(&g);
+        let base = components(n, edges.iter().copied());
+        // bridge iff removal disconnects
+        for e in 0..m {
+            let c = components(n, (0..m).filter(|&i| i != e).map(|i| edges[i]));
+            assert_eq!(l.bridge[e], c > base, "{:?} {}", edges, e);
+        }
+        // cut iff removal of vertex increases components among others
+        for v in 0..n {
+            let rest = edges.iter().copied().filter(|&(x, y)| x != v && y != v);
+            let isolated = edges.iter().all(|&(x, y)| x != v && y != v) as usize;
+            let c = components(n, rest) - 1 + isolated;
+            assert_eq!(l.cut[v], c > base, "{:?} {}", edges, v);
+        }
+        // 2ecc = dsu over non-bridge edges
+        let (count, id) = l.two_edge_components(&g);
+        let mut d = Dsu::new(n);
+        for (e, &(u, v)) in edges.iter().enumerate() {
+            if !l.bridge[e] {
+                d.join(u, v);
+            }
+        }
+        assert_eq!(count, d.components());
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(id[u] == id[v], d.check(u, v));
+            }
+        }
+        // block-cut tree is a forest, cuts are exactly vertices in >= 2 blocks
+        let t = l.block_cut_tree(&g);
+        let tn = t.len();
+        let te = t.edges();
+        assert_eq!(
+            te + components(
+                tn,
+                (0..tn).flat_map(|u| t.adj(u).iter().map(move |e| (u, e.to)))
+            ),
+            tn
+        );
+        let bv = l.block_vertices(&g);
+        for v in 0..n {
+            // self-loop blocks aside
+            let k = bv.iter().filter(|b| b.len() > 1 && b.contains(&v)).count();
+            assert_eq!(l.cut[v], k >= 2);
+        }
+        for (e, &(u, v)) in edges.iter().enumerate() {
+            assert!(bv[l.block[e]].contains(&u) && bv[l.block[e]].contains(&v));
+            if l.bridge[e] {
+                assert_eq!(bv[l.block[e]].len(), 2);
+            }
+        }
+    }
+}
+
+#[test]
+fn multi_edge_and_loop() {
+    // 0 = 1 - 2, loop on 2
+    let g = Csr::undirected(3, &[(0, 1), (1, 0), (1, 2), (2, 2)]);
+    let l = LowLink::new(&g);
+    assert_eq!(l.bridges(), vec![2]);
+    assert_eq!(l.articulation_points(), vec![1]);
+    assert_eq!(l.blocks, 3);
+    assert_eq!(l.block[0], l.block[1]);
+    assert_eq!(l.two_edge_components(&g), (2, vec![0, 0, 1]));
+}
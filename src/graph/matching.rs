@@ -0,0 +1,212 @@
+use crate::core::num::Num;
+use std::collections::VecDeque;
+
+/// Maximum bipartite matching by Hopcroft–Karp, left `0..l`, right `0..r`.
+///
+/// # Time complexity
+///
+/// *O*(*m* sqrt *n*).
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::HopcroftKarp;
+///
+/// let m = HopcroftKarp::new(2, 2, &[(0, 0), (0, 1), (1, 0)]);
+/// assert_eq!(m.len(), 2);
+/// assert_eq!(m.pairs(), vec![(0, 1), (1, 0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HopcroftKarp {
+    g: Vec<Vec<usize>>,
+    pub mate_l: Vec<Option<usize>>,
+    pub mate_r: Vec<Option<usize>>,
+    len: usize,
+}
+
+impl HopcroftKarp {
+    /// `edges` as `(left, right)`.
+    pub fn new(l: usize, r: usize, edges: &[(usize, usize)]) -> Self {
+        let mut g = vec![vec![]; l];
+        for &(u, v) in edges {
+            g[u].push(v);
+        }
+        let mut res = Self {
+            g,
+            mate_l: vec![None; l],
+            mate_r: vec![None; r],
+            len: 0,
+        };
+        let mut dist = vec![0; l];
+        while res.bfs(&mut dist) {
+            let mut it = vec![0; l];
+            for u in 0..l {
+                if res.mate_l[u].is_none() && res.dfs(u, &dist, &mut it) {
+                    res.len += 1;
+                }
+            }
+        }
+        res
+    }
+    /// Size of matching.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Matched `(left, right)`, by left.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        (0..self.mate_l.len())
+            .filter_map(|u| self.mate_l[u].map(|v| (u, v)))
+            .collect()
+    }
+    /// Minimum vertex cover by König, ret: `(left, right)` vertices, of size `len`.
+    pub fn vertex_cover(&self) -> (Vec<usize>, Vec<usize>) {
+        // alternating reachable from free left vertices
+        let (l, r) = (self.mate_l.len(), self.mate_r.len());
+        let mut vl = vec![false; l];
+        let mut vr = vec![false; r];
+        let mut q: VecDeque<_> = (0..l).filter(|&u| self.mate_l[u].is_none()).collect();
+        for &u in &q {
+            vl[u] = true;
+        }
+        while let Some(u) = q.pop_front() {
+            for &v in &self.g[u] {
+                if !vr[v] {
+                    vr[v] = true;
+                    if let Some(w) = self.mate_r[v] {
+                        if !vl[w] {
+                            vl[w] = true;
+                            q.push_back(w);
+                        }
+                    }
+                }
+            }
+        }
+        (
+            (0..l).filter(|&u| !vl[u]).collect(),
+            (0..r).filter(|&v| vr[v]).collect(),
+        )
+    }
+
+    /// Layer left vertices from free ones, ret: whether augmenting path exists.
+    fn bfs(&self, dist: &mut [usize]) -> bool {
+        let l = self.g.len();
+        let mut q = VecDeque::new();
+        for (u, d) in dist.iter_mut().enumerate() {
+            if self.mate_l[u].is_none() {
+                *d = 0;
+                q.push_back(u);
+            } else {
+                *d = l;
+            }
+        }
+        let mut found = false;
+        while let Some(u) = q.pop_front() {
+            for &v in &self.g[u] {
+                match self.mate_r[v] {
+                    None => found = true,
+                    Some(w) if dist[w] == l => {
+                        dist[w] = dist[u] + 1;
+                        q.push_back(w);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        found
+    }
+    fn dfs(&mut self, u: usize, dist: &[usize], it: &mut [usize]) -> bool {
+        while it[u] < self.g[u].len() {
+            let v = self.g[u][it[u]];
+            it[u] += 1;
+            let ok = match self.mate_r[v] {
+                None => true,
+                Some(w) => dist[w] == dist[u] + 1 && self.dfs(w, dist, it),
+            };
+            if ok {
+                self.mate_l[u] = Some(v);
+                self.mate_r[v] = Some(u);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Min cost assignment of `n` rows to distinct columns of `a[n][m]`, `n <= m`.
+///
+/// Ret: `(cost, col)`, row `i` assigned to `col[i]`. Require signed `T`, float allowed.
+///
+/// # Time complexity
+///
+/// *O*(*n*^2 *m*).
+pub fn hungarian<T>(a: &[Vec<T>]) -> (T, Vec<usize>)
+where
+    T: Num + PartialOrd + Copy,
+{
+    let n = a.len();
+    let m = a.first().map_or(0, |r| r.len());
+    assert!(n <= m);
+    // 1-based, column 0 as virtual, `p[j]` row of column `j`
+    let mut u = vec![T::ZERO; n + 1];
+    let mut v = vec![T::ZERO; m + 1];
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv: Vec<Option<T>> = vec![None; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = None;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let cur = a[i0 - 1][j - 1] - u[i0] - v[j];
+                if !matches!(minv[j], Some(x) if x <= cur) {
+                    minv[j] = Some(cur);
+                    way[j] = j0;
+                }
+                if !matches!(delta, Some(d) if d <= minv[j].unwrap()) {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+            let delta = delta.unwrap();
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] = u[p[j]] + delta;
+                    v[j] = v[j] - delta;
+                } else {
+                    minv[j] = minv[j].map(|x| x - delta);
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+    let mut col = vec![0; n];
+    for j in 1..=m {
+        if p[j] != 0 {
+            col[p[j] - 1] = j - 1;
+        }
+    }
+    let cost = (0..n).fold(T::ZERO, |s, i| s + a[i][col[i]]);
+    (cost, col)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+use crate::core::next_permutation;
+use crate::core::Rng;
+use crate::graph::MaxFlow;
+
+#[test]
+fn hopcroft_karp() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let l = 1 + rng.gen() as usize % 10;
+        let r = 1 + rng.gen() as usize % 10;
+        let m = rng.gen() as usize % 30;
+        let edges: Vec<_> = (0..m)
+            .map(|_| (rng.gen() as usize % l, rng.gen() as usize % r))
+            .collect();
+        let hk = HopcroftKarp::new(l, r, &edges);
+        let mut f = MaxFlow::new(l + r + 2);
+        let (s, t) = (l + r, l + r + 1);
+        for u in 0..l {
+            f.add_edge(s, u, 1);
+        }
+        for v in 0..r {
+            f.add_edge(l + v, t, 1);
+        }
+        for &(u, v) in &edges {
+            f.add_edge(u, l + v, 1);
+        }
+        assert_eq!(hk.len(), f.flow(s, t));
+        let pairs = hk.pairs();
+        assert_eq!(pairs.len(), hk.len());
+        for &(u, v) in &pairs {
+            assert!(edges.contains(&(u, v)));
+            assert_eq!(hk.mate_r[v], Some(u));
+        }
+        let (cl, cr) = hk.vertex_cover();
+        assert_eq!(cl.len() + cr.len(), hk.len());
+        for &(u, v) in &edges {
+            assert!(cl.contains(&u) || cr.contains(&v));
+        }
+    }
+}
+
+fn brute<T: Num + PartialOrd + Copy>(a: &[Vec<T>]) -> T {
+    let (n, m) = (a.len(), a[0].len());
+    let mut p: Vec<_> = (0..m).collect();
+    let mut best = None;
+    loop {
+        let c = (0..n).fold(T::ZERO, |s, i| s + a[i][p[i]]);
+        if !matches!(best, Some(b) if b <= c) {
+            best = Some(c);
+        }
+        if !next_permutation(&mut p) {
+            break;
+        }
+    }
+    best.unwrap()
+}
+
+#[test]
+fn hungarian_int() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let n = 1 + rng.gen() as usize % 5;
+        let m = n + rng.gen() as usize % 3;
+        let a: Vec<Vec<i64>> = (0..n)
+            .map(|_| (0..m).map(|_| (rng.gen() % 41) as i64 - 20).collect())
+            .collect();
+        let (c, col) = hungarian(&a);
+        assert_eq!(c, brute(&a));
+        assert_eq!(c, (0..n).map(|i| a[i][col[i]]).sum::<i64>());
+        let mut used = col.clone();
+        used.sort_unstable();
+        used.dedup();
+        assert_eq!(used.len(), n);
+    }
+}
+
+#[test]
+fn hungarian_f64() {
+    let rng = Rng::new();
+    for _ in 0..50 {
+        let n = 1 + rng.gen() as usize % 5;
+        let a: Vec<Vec<f64>> = (0..n)
+            .map(|_| (0..n).map(|_| (rng.gen() % 1000) as f64 / 7.0).collect())
+            .collect();
+        let (c, _) = hungarian(&a);
+        assert!((c - brute(&a)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::num::Integer;
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlowEdge<T> {
+    pub from: usize,
+    pub to: usize,
+    pub cap: T,
+    pub flow: T,
+}
+
+/// Residual arc, reverse of arc `i` is `i ^ 1`, `cost` for `MinCostFlow`.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Arc<T, C = ()> {
+    pub to: usize,
+    pub cap: T,
+    pub cost: C,
+}
+
+/// Dinic's max flow.
+///
+/// # Time complexity
+///
+/// *O*(*n*^2 *m*), *O*(*m* sqrt *m*) for unit capacities.
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::MaxFlow;
+///
+/// let mut f = MaxFlow::new(3);
+/// let e = f.add_edge(0, 1, 3);
+/// f.add_edge(1, 2, 2);
+/// f.add_edge(0, 2, 1);
+/// assert_eq!(f.flow(0, 2), 3);
+/// assert_eq!(f.edge(e).flow, 2);
+/// assert_eq!(f.min_cut(0), vec![true, true, false]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaxFlow<T> {
+    g: Vec<Vec<usize>>,
+    a: Vec<Arc<T>>,
+    level: Vec<usize>,
+    it: Vec<usize>,
+}
+
+impl<T: Integer + Copy> MaxFlow<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            g: vec![vec![]; n],
+            a: vec![],
+            level: vec![0; n],
+            it: vec![0; n],
+        }
+    }
+    /// ret: edge id.
+    pub fn add_edge(&mut self, u: usize, v: usize, cap: T) -> usize {
+        debug_assert!(cap >= T::ZERO);
+        let id = self.a.len() >> 1;
+        self.g[u].push(self.a.len());
+        self.a.push(Arc {
+            to: v,
+            cap,
+            cost: (),
+        });
+        self.g[v].push(self.a.len());
+        self.a.push(Arc {
+            to: u,
+            cap: T::ZERO,
+            cost: (),
+        });
+        id
+    }
+    pub fn edge(&self, id: usize) -> FlowEdge<T> {
+        let (x, y) = (self.a[id << 1], self.a[id << 1 | 1]);
+        FlowEdge {
+            from: y.to,
+            to: x.to,
+            cap: x.cap + y.cap,
+            flow: y.cap,
+        }
+    }
+    pub fn edges(&self) -> Vec<FlowEdge<T>> {
+        (0..self.a.len() >> 1).map(|i| self.edge(i)).collect()
+    }
+    /// Push as much as possible, on top of existing flow.
+    pub fn flow(&mut self, s: usize, t: usize) -> T {
+        self.run(s, t, None)
+    }
+    /// Push up to `limit`.
+    pub fn flow_with_limit(&mut self, s: usize, t: usize, limit: T) -> T {
+        self.run(s, t, Some(limit))
+    }
+    /// `true` for source side, i.e. reachable from `s` in residual graph.
+    pub fn min_cut(&self, s: usize) -> Vec<bool> {
+        let mut vis = vec![false; self.g.len()];
+        vis[s] = true;
+        let mut st = vec![s];
+        while let Some(u) = st.pop() {
+            for &i in &self.g[u] {
+                let Arc { to, cap, .. } = self.a[i];
+                if cap > T::ZERO && !vis[to] {
+                    vis[to] = true;
+                    st.push(to);
+                }
+            }
+        }
+        vis
+    }
+
+    /// Augment up to `limit`, or until `t` unreachable if `None`.
+    fn run(&mut self, s: usize, t: usize, limit: Option<T>) -> T {
+        debug_assert_ne!(s, t);
+        let mut res = T::ZERO;
+        while !matches!(limit, Some(l) if res >= l) && self.bfs(s, t) {
+            for x in self.it.iter_mut() {
+                *x = 0;
+            }
+            loop {
+                let f = self.dfs(s, t, limit.map(|l| l - res));
+                if f == T::ZERO {
+                    break;
+                }
+                res = res + f;
+            }
+        }
+        res
+    }
+    fn bfs(&mut self, s: usize, t: usize) -> bool {
+        let n = self.g.len();
+        self.level = vec![n; n];
+        self.level[s] = 0;
+        let mut q = VecDeque::new();
+        q.push_back(s);
+        while let Some(u) = q.pop_front() {
+            for &i in &self.g[u] {
+                let Arc { to, cap, .. } = self.a[i];
+                if cap > T::ZERO && self.level[to] == n {
+                    self.level[to] = self.level[u] + 1;
+                    q.push_back(to);
+                }
+            }
+        }
+        self.level[t] != n
+    }
+    /// `up` of `None` for unbounded, only at `s`.
+    fn dfs(&mut self, u: usize, t: usize, up: Option<T>) -> T {
+        if u == t {
+            return up.unwrap();
+        }
+        while self.it[u] < self.g[u].len() {
+            let i = self.g[u][self.it[u]];
+            let Arc { to, cap, .. } = self.a[i];
+            if cap > T::ZERO && self.level[to] == self.level[u] + 1 {
+                let up = match up {
+                    Some(x) if x < cap => x,
+                    _ => cap,
+                };
+                let f = self.dfs(to, t, Some(up));
+                if f > T::ZERO {
+                    self.a[i].cap = self.a[i].cap - f;
+                    self.a[i ^ 1].cap = self.a[i ^ 1].cap + f;
+                    return f;
+                }
+            }
+            self.it[u] += 1;
+        }
+        T::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+use crate::core::Rng;
+
+#[test]
+fn vs_brute_cut() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let n = 2 + rng.gen() as usize % 7;
+        let m = rng.gen() as usize % 20;
+        let edges: Vec<_> = (0..m)
+            .map(|_| {
+                (
+                    rng.gen() as usize % n,
+                    rng.gen() as usize % n,
+                    (rng.gen() % 10) as i64,
+                )
+            })
+            .collect();
+        let (s, t) = (0, n - 1);
+        let mut f = MaxFlow::new(n);
+        for &(u, v, c) in &edges {
+            f.add_edge(u, v, c);
+        }
+        let res = f.flow(s, t);
+        // min over s-t cuts
+        let brute = (0..1_usize << n)
+            .filter(|&S| S >> s & 1 == 1 && S >> t & 1 == 0)
+            .map(|S| {
+                edges
+                    .iter()
+                    .filter(|e| S >> e.0 & 1 == 1 && S >> e.1 & 1 == 0)
+                    .map(|e| e.2)
+                    .sum::<i64>()
+            })
+            .min()
+            .unwrap();
+        assert_eq!(res, brute);
+        // conservation and capacity
+        let mut net = vec![0; n];
+        for (i, e) in f.edges().into_iter().enumerate() {
+            assert_eq!((e.from, e.to, e.cap), edges[i]);
+            assert!(0 <= e.flow && e.flow <= e.cap);
+            net[e.from] -= e.flow;
+            net[e.to] += e.flow;
+        }
+        for v in 0..n {
+            if v != s && v != t {
+                assert_eq!(net[v], 0);
+            }
+        }
+        assert_eq!(net[t], res);
+        // cut found has capacity of flow
+        let cut = f.min_cut(s);
+        assert!(cut[s] && !cut[t]);
+        let c: i64 = edges
+            .iter()
+            .filter(|e| cut[e.0] && !cut[e.1])
+            .map(|e| e.2)
+            .sum();
+        assert_eq!(c, res);
+        // already max
+        assert_eq!(f.flow(s, t), 0);
+    }
+}
+
+#[test]
+fn limit_then_rest() {
+    let mut f = MaxFlow::new(4);
+    f.add_edge(0, 1, 5_u32);
+    f.add_edge(0, 2, 5);
+    f.add_edge(1, 3, 4);
+    f.add_edge(2, 3, 7);
+    assert_eq!(f.flow_with_limit(0, 3, 3), 3);
+    assert_eq!(f.flow(0, 3), 6);
+}
+
+#[test]
+fn infinite_source_edges() {
+    let mut f = MaxFlow::new(5);
+    for v in 1..4 {
+        f.add_edge(0, v, i64::MAX);
+        f.add_edge(v, 4, 10);
+    }
+    assert_eq!(f.flow_with_limit(0, 4, 5), 5);
+    assert_eq!(f.flow(0, 4), 25);
+    assert_eq!(f.flow_with_limit(0, 4, i64::MAX), 0);
+}
@@ -0,0 +1,171 @@
+use super::max_flow::Arc;
+use crate::core::num::Integer;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CostEdge<T> {
+    pub from: usize,
+    pub to: usize,
+    pub cap: T,
+    pub flow: T,
+    pub cost: T,
+}
+
+/// Min cost flow by successive shortest paths, Dijkstra on Johnson potentials.
+///
+/// Require non-negative costs, and signed `T` as reverse arcs carry negated costs.
+///
+/// # Time complexity
+///
+/// *O*(*F* *m* log *m*), `F` for number of augmentations, bounded by flow.
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::MinCostFlow;
+///
+/// let mut f = MinCostFlow::new(3);
+/// f.add_edge(0, 1, 2, 1);
+/// f.add_edge(1, 2, 2, 1);
+/// f.add_edge(0, 2, 1, 5);
+/// assert_eq!(f.slope(0, 2, 10), vec![(0, 0), (2, 4), (3, 9)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinCostFlow<T> {
+    g: Vec<Vec<usize>>,
+    a: Vec<Arc<T, T>>,
+    /// Potentials, kept valid across calls.
+    h: Vec<T>,
+}
+
+impl<T: Integer + Copy> MinCostFlow<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            g: vec![vec![]; n],
+            a: vec![],
+            h: vec![T::ZERO; n],
+        }
+    }
+    /// ret: edge id.
+    pub fn add_edge(&mut self, u: usize, v: usize, cap: T, cost: T) -> usize {
+        debug_assert!(cap >= T::ZERO && cost >= T::ZERO);
+        let id = self.a.len() >> 1;
+        self.g[u].push(self.a.len());
+        self.a.push(Arc { to: v, cap, cost });
+        self.g[v].push(self.a.len());
+        self.a.push(Arc {
+            to: u,
+            cap: T::ZERO,
+            cost: T::ZERO - cost,
+        });
+        id
+    }
+    pub fn edge(&self, id: usize) -> CostEdge<T> {
+        let (x, y) = (self.a[id << 1], self.a[id << 1 | 1]);
+        CostEdge {
+            from: y.to,
+            to: x.to,
+            cap: x.cap + y.cap,
+            flow: y.cap,
+            cost: x.cost,
+        }
+    }
+    pub fn edges(&self) -> Vec<CostEdge<T>> {
+        (0..self.a.len() >> 1).map(|i| self.edge(i)).collect()
+    }
+    /// Max flow with min cost, ret: `(flow, cost)`.
+    pub fn flow(&mut self, s: usize, t: usize) -> (T, T) {
+        *self.run(s, t, None).last().unwrap()
+    }
+    /// Flow up to `limit` with min cost, ret: `(flow, cost)`.
+    pub fn flow_with_limit(&mut self, s: usize, t: usize, limit: T) -> (T, T) {
+        *self.slope(s, t, limit).last().unwrap()
+    }
+    /// Breakpoints of min cost as a function of flow up to `limit`, convex, from `(0, 0)`.
+    ///
+    /// On top of previous calls with same `s`, so long as no edge added since.
+    pub fn slope(&mut self, s: usize, t: usize, limit: T) -> Vec<(T, T)> {
+        self.run(s, t, Some(limit))
+    }
+
+    /// Slope up to `limit`, or until `t` unreachable if `None`.
+    fn run(&mut self, s: usize, t: usize, limit: Option<T>) -> Vec<(T, T)> {
+        debug_assert_ne!(s, t);
+        let n = self.g.len();
+        let mut h = std::mem::take(&mut self.h);
+        let mut res = vec![(T::ZERO, T::ZERO)];
+        let (mut flow, mut cost) = (T::ZERO, T::ZERO);
+        let mut last = None;
+        while !matches!(limit, Some(l) if flow >= l) {
+            // dijkstra on reduced costs, `pre` as arc into vertex
+            let mut dist: Vec<Option<T>> = vec![None; n];
+            let mut pre = vec![0; n];
+            let mut done = vec![false; n];
+            let mut pq = BinaryHeap::new();
+            dist[s] = Some(T::ZERO);
+            pq.push(Reverse((T::ZERO, s)));
+            while let Some(Reverse((d, u))) = pq.pop() {
+                if done[u] {
+                    continue;
+                }
+                done[u] = true;
+                for &i in &self.g[u] {
+                    let Arc { to, cap, cost } = self.a[i];
+                    if cap == T::ZERO {
+                        continue;
+                    }
+                    let nd = d + cost + h[u] - h[to];
+                    if !matches!(dist[to], Some(x) if x <= nd) {
+                        dist[to] = Some(nd);
+                        pre[to] = i;
+                        pq.push(Reverse((nd, to)));
+                    }
+                }
+            }
+            let dt = match dist[t] {
+                Some(d) => d,
+                None => break,
+            };
+            for v in 0..n {
+                // keep reduced costs non-negative
+                let d = match dist[v] {
+                    Some(d) if d < dt => d,
+                    _ => dt,
+                };
+                h[v] = h[v] + d;
+            }
+            let mut f = limit.map(|l| l - flow);
+            let mut v = t;
+            while v != s {
+                let i = pre[v];
+                if !matches!(f, Some(x) if x <= self.a[i].cap) {
+                    f = Some(self.a[i].cap);
+                }
+                v = self.a[i ^ 1].to;
+            }
+            let f = f.unwrap();
+            let mut v = t;
+            while v != s {
+                let i = pre[v];
+                self.a[i].cap = self.a[i].cap - f;
+                self.a[i ^ 1].cap = self.a[i ^ 1].cap + f;
+                v = self.a[i ^ 1].to;
+            }
+            // unit cost of this path
+            let c = h[t] - h[s];
+            flow = flow + f;
+            cost = cost + f * c;
+            if last == Some(c) {
+                res.pop();
+            }
+            res.push((flow, cost));
+            last = Some(c);
+        }
+        self.h = h;
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests;
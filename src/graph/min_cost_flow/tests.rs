@@ -0,0 +1,75 @@
+use super::*;
+use crate::core::Rng;
+use crate::graph::{floyd_warshall, Csr, MaxFlow};
+
+#[test]
+fn optimality() {
+    let rng = Rng::new();
+    for _ in 0..200 {
+        let n = 2 + rng.gen() as usize % 6;
+        let m = rng.gen() as usize % 15;
+        let edges: Vec<_> = (0..m)
+            .map(|_| {
+                (
+                    rng.gen() as usize % n,
+                    rng.gen() as usize % n,
+                    (rng.gen() % 5) as i64,
+                    (rng.gen() % 10) as i64,
+                )
+            })
+            .collect();
+        let (s, t) = (0, n - 1);
+        let mut f = MinCostFlow::new(n);
+        let mut mf = MaxFlow::new(n);
+        for &(u, v, c, w) in &edges {
+            f.add_edge(u, v, c, w);
+            mf.add_edge(u, v, c);
+        }
+        let slope = f.slope(s, t, 1 << 40);
+        let (flow, cost) = *slope.last().unwrap();
+        assert_eq!(flow, mf.flow(s, t));
+        // convex, strictly increasing unit cost
+        for w in slope.windows(3) {
+            let d0 = (w[1].1 - w[0].1) * (w[2].0 - w[1].0);
+            let d1 = (w[2].1 - w[1].1) * (w[1].0 - w[0].0);
+            assert!(d0 < d1);
+        }
+        let es = f.edges();
+        assert_eq!(cost, es.iter().map(|e| e.flow * e.cost).sum::<i64>());
+        // optimal iff no negative cycle in residual graph
+        let mut res = vec![];
+        for e in &es {
+            if e.flow < e.cap {
+                res.push((e.from, e.to, e.cost));
+            }
+            if e.flow > 0 {
+                res.push((e.to, e.from, -e.cost));
+            }
+        }
+        assert!(floyd_warshall(&Csr::directed(n, &res)).is_some());
+    }
+}
+
+#[test]
+fn limited() {
+    let mut f = MinCostFlow::new(4);
+    f.add_edge(0, 1, 2, 1);
+    f.add_edge(0, 2, 2, 3);
+    f.add_edge(1, 3, 1, 1);
+    f.add_edge(2, 3, 3, 0);
+    f.add_edge(1, 2, 2, 1);
+    // paths cost 2, 2, 3, 3
+    assert_eq!(f.flow_with_limit(0, 3, 3), (3, 7));
+    assert_eq!(f.flow(0, 3), (1, 3));
+}
+
+#[test]
+fn infinite_source_edges() {
+    let mut f = MinCostFlow::new(5);
+    for v in 1..4 {
+        f.add_edge(0, v, i64::MAX, v as i64);
+        f.add_edge(v, 4, 10, 0);
+    }
+    assert_eq!(f.flow(0, 4), (30, 60));
+    assert_eq!(f.flow_with_limit(0, 4, i64::MAX), (0, 0));
+}
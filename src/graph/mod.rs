@@ -0,0 +1,28 @@
+//! Graph algorithms, on `Csr` adjacency.
+mod csr;
+#[cfg(test)]
+mod fixture;
+mod hld;
+mod lowlink;
+mod matching;
+mod max_flow;
+mod min_cost_flow;
+mod mst;
+mod scc;
+mod shortest_path;
+mod topo;
+mod tree;
+
+pub use self::csr::{Bfs, Components, Csr, Dfs, Edge, IntoEdge};
+pub use self::hld::{Hld, HldLazy, HldSeg};
+pub use self::lowlink::LowLink;
+pub use self::matching::{hungarian, HopcroftKarp};
+pub use self::max_flow::{FlowEdge, MaxFlow};
+pub use self::min_cost_flow::{CostEdge, MinCostFlow};
+pub use self::mst::{msf_kruskal, mst_kruskal, mst_prim};
+pub use self::scc::{scc, Scc, TwoSat};
+pub use self::shortest_path::{
+    bellman_ford, bfs01, dijkstra, dijkstra_radix, floyd_warshall, ShortestPaths,
+};
+pub use self::topo::{dag_count_paths, dag_longest_path, toposort, toposort_lex_min};
+pub use self::tree::{EulerLca, Lca, LiftingLca, RootedTree};
@@ -0,0 +1,80 @@
+use crate::core::num::Num;
+use crate::ds::dsu::Dsu;
+
+/// Minimum spanning forest, ret: `(weight, edge indexes)`.
+///
+/// # Time complexity
+///
+/// *O*(*m* log *m*).
+pub fn msf_kruskal<W>(n: usize, edges: &[(usize, usize, W)]) -> (W, Vec<usize>)
+where
+    W: Num + Ord + Copy,
+{
+    let mut id: Vec<usize> = (0..edges.len()).collect();
+    id.sort_by_key(|&i| edges[i].2);
+    let mut dsu = Dsu::new(n);
+    let mut sum = W::ZERO;
+    let mut res = vec![];
+    for i in id {
+        let (u, v, w) = edges[i];
+        if !dsu.join(u, v) {
+            sum = sum + w;
+            res.push(i);
+        }
+    }
+    (sum, res)
+}
+
+/// Minimum spanning tree, `None` if disconnected.
+pub fn mst_kruskal<W>(n: usize, edges: &[(usize, usize, W)]) -> Option<(W, Vec<usize>)>
+where
+    W: Num + Ord + Copy,
+{
+    let res = msf_kruskal(n, edges);
+    if res.1.len() + 1 < n {
+        return None;
+    }
+    Some(res)
+}
+
+/// Dense Prim on complete graph with weight `w(u, v)`, ret: `(weight, edges as (u, v))`.
+///
+/// # Time complexity
+///
+/// *O*(*n*^2).
+pub fn mst_prim<W, F>(n: usize, w: F) -> (W, Vec<(usize, usize)>)
+where
+    W: Num + Ord + Copy,
+    F: Fn(usize, usize) -> W,
+{
+    let mut sum = W::ZERO;
+    let mut res = vec![];
+    if n == 0 {
+        return (sum, res);
+    }
+    // (cost, from) to join the tree
+    let mut best: Vec<_> = (0..n).map(|v| (w(0, v), 0)).collect();
+    let mut done = vec![false; n];
+    done[0] = true;
+    for _ in 1..n {
+        let v = (0..n)
+            .filter(|&v| !done[v])
+            .min_by_key(|&v| best[v].0)
+            .unwrap();
+        done[v] = true;
+        sum = sum + best[v].0;
+        res.push((best[v].1, v));
+        for u in 0..n {
+            if !done[u] {
+                let x = w(v, u);
+                if x < best[u].0 {
+                    best[u] = (x, v);
+                }
+            }
+        }
+    }
+    (sum, res)
+}
+
+#[cfg(test)]
+mod tests;
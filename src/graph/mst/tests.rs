@@ -0,0 +1,71 @@
+use super::*;
+use crate::core::Rng;
+
+/// Min over all spanning forests, by subsets.
+fn brute(n: usize, edges: &[(usize, usize, i64)]) -> i64 {
+    let m = edges.len();
+    let mut best = None;
+    for s in 0..1_usize << m {
+        let mut d = Dsu::new(n);
+        let mut ok = true;
+        let mut sum = 0;
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            if s >> i & 1 == 1 {
+                ok &= !d.join(u, v);
+                sum += w;
+            }
+        }
+        // forest, and maximal
+        if ok && edges.iter().all(|&(u, v, _)| d.check(u, v)) {
+            best = Some(best.map_or(sum, |b: i64| b.min(sum)));
+        }
+    }
+    best.unwrap()
+}
+
+#[test]
+fn kruskal() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let n = 1 + rng.gen() as usize % 6;
+        let m = rng.gen() as usize % 10;
+        let edges: Vec<_> = (0..m)
+            .map(|_| {
+                let u = rng.gen() as usize % n;
+                let v = rng.gen() as usize % n;
+                (u, v, (rng.gen() % 21) as i64 - 10)
+            })
+            .collect();
+        let (w, ids) = msf_kruskal(n, &edges);
+        assert_eq!(w, brute(n, &edges));
+        assert_eq!(w, ids.iter().map(|&i| edges[i].2).sum::<i64>());
+        let mut d = Dsu::new(n);
+        for &i in &ids {
+            assert!(!d.join(edges[i].0, edges[i].1));
+        }
+        let connected = d.components() == 1;
+        assert_eq!(mst_kruskal(n, &edges).is_some(), connected);
+    }
+}
+
+#[test]
+fn prim_vs_kruskal() {
+    let rng = Rng::new();
+    for _ in 0..20 {
+        let n = rng.gen() as usize % 30;
+        let p: Vec<(i64, i64)> = (0..n)
+            .map(|_| ((rng.gen() % 100) as i64, (rng.gen() % 100) as i64))
+            .collect();
+        let w = |i: usize, j: usize| (p[i].0 - p[j].0).abs() + (p[i].1 - p[j].1).abs();
+        let mut edges = vec![];
+        for i in 0..n {
+            for j in 0..i {
+                edges.push((i, j, w(i, j)));
+            }
+        }
+        let (s, e) = mst_prim(n, w);
+        assert_eq!(e.len(), n.saturating_sub(1));
+        assert_eq!(s, e.iter().map(|&(u, v)| w(u, v)).sum::<i64>());
+        assert_eq!(Some(s), mst_kruskal(n, &edges).map(|r| r.0));
+    }
+}
@@ -0,0 +1,152 @@
+use super::Csr;
+
+/// Strongly connected components.
+#[derive(Clone, Debug)]
+pub struct Scc {
+    /// `id[v]` of component, in topological order, i.e. edge `u -> v` implies `id[u] <= id[v]`.
+    pub id: Vec<usize>,
+    pub count: usize,
+}
+
+/// Tarjan's, iterative.
+///
+/// # Time complexity
+///
+/// *O*(*n* + *m*).
+pub fn scc<W: Copy>(g: &Csr<W>) -> Scc {
+    let n = g.len();
+    const NIL: usize = usize::MAX;
+    let mut tin = vec![NIL; n];
+    let mut low = vec![0; n];
+    let mut id = vec![NIL; n];
+    let mut st = vec![];
+    let mut count = 0;
+    let mut timer = 0;
+    for s in 0..n {
+        if tin[s] != NIL {
+            continue;
+        }
+        let mut call = vec![(s, 0)];
+        tin[s] = timer;
+        low[s] = timer;
+        timer += 1;
+        st.push(s);
+        while let Some(&mut (u, ref mut i)) = call.last_mut() {
+            if let Some(e) = g.adj(u).get(*i) {
+                *i += 1;
+                let v = e.to;
+                if tin[v] == NIL {
+                    tin[v] = timer;
+                    low[v] = timer;
+                    timer += 1;
+                    st.push(v);
+                    call.push((v, 0));
+                } else if id[v] == NIL {
+                    // on stack
+                    low[u] = low[u].min(tin[v]);
+                }
+                continue;
+            }
+            call.pop();
+            if let Some(&(p, _)) = call.last() {
+                low[p] = low[p].min(low[u]);
+            }
+            if low[u] == tin[u] {
+                loop {
+                    let v = st.pop().unwrap();
+                    id[v] = count;
+                    if v == u {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+    // found in reverse topological order
+    for x in id.iter_mut() {
+        *x = count - 1 - *x;
+    }
+    Scc { id, count }
+}
+
+impl Scc {
+    /// Members of each component.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut res = vec![vec![]; self.count];
+        for (v, &c) in self.id.iter().enumerate() {
+            res[c].push(v);
+        }
+        res
+    }
+    /// Condensed DAG on component ids, without duplicate edges, `id` of edges meaningless.
+    pub fn condensation<W: Copy>(&self, g: &Csr<W>) -> Csr {
+        let mut edges = vec![];
+        for u in 0..g.len() {
+            for e in g.adj(u) {
+                let (x, y) = (self.id[u], self.id[e.to]);
+                if x != y {
+                    edges.push((x, y));
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        Csr::directed(self.count, &edges)
+    }
+}
+
+/// 2-SAT, variables `x_0..x_n`.
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::TwoSat;
+///
+/// let mut s = TwoSat::new(2);
+/// // x0 | x1, !x0 | x1, x0 | !x1
+/// s.add_clause(0, true, 1, true);
+/// s.add_clause(0, false, 1, true);
+/// s.add_clause(0, true, 1, false);
+/// assert_eq!(s.solve(), Some(vec![true, true]));
+/// s.add_clause(0, false, 1, false);
+/// assert_eq!(s.solve(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TwoSat {
+    n: usize,
+    edges: Vec<(usize, usize)>,
+}
+
+impl TwoSat {
+    pub fn new(n: usize) -> Self {
+        Self { n, edges: vec![] }
+    }
+    #[inline]
+    fn node(x: usize, v: bool) -> usize {
+        x << 1 | v as usize
+    }
+    /// Require `(x_a == va) | (x_b == vb)`.
+    pub fn add_clause(&mut self, a: usize, va: bool, b: usize, vb: bool) {
+        self.edges.push((Self::node(a, !va), Self::node(b, vb)));
+        self.edges.push((Self::node(b, !vb), Self::node(a, va)));
+    }
+    /// Any satisfying assignment.
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        let s = scc(&Csr::directed(self.n << 1, &self.edges));
+        (0..self.n)
+            .map(|x| {
+                let (f, t) = (s.id[Self::node(x, false)], s.id[Self::node(x, true)]);
+                // later in topological order can't imply the other
+                if f == t {
+                    None
+                } else {
+                    Some(f < t)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests;
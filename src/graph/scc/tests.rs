@@ -0,0 +1,87 @@
+use super::*;
+use crate::core::Rng;
+use crate::graph::fixture::random_edges;
+
+fn reach(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<bool>> {
+    let mut r = vec![vec![false; n]; n];
+    for (i, r) in r.iter_mut().enumerate() {
+        r[i] = true;
+    }
+    for &(u, v) in edges {
+        r[u][v] = true;
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                r[i][j] |= r[i][k] && r[k][j];
+            }
+        }
+    }
+    r
+}
+
+#[test]
+fn random() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let n = 1 + rng.gen() as usize % 15;
+        let m = rng.gen() as usize % 30;
+        let edges = random_edges(&rng, n, m);
+        let g = Csr::directed(n, &edges);
+        let s = scc(&g);
+        let r = reach(n, &edges);
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(s.id[u] == s.id[v], r[u][v] && r[v][u]);
+            }
+        }
+        for &(u, v) in &edges {
+            assert!(s.id[u] <= s.id[v]);
+        }
+        assert_eq!(s.groups().iter().map(|g| g.len()).sum::<usize>(), n);
+        let d = s.condensation(&g);
+        assert_eq!(d.len(), s.count);
+        for x in 0..d.len() {
+            for e in d.adj(x) {
+                assert!(x < e.to);
+            }
+        }
+        let mut de: Vec<_> = edges
+            .iter()
+            .map(|&(u, v)| (s.id[u], s.id[v]))
+            .filter(|e| e.0 != e.1)
+            .collect();
+        de.sort_unstable();
+        de.dedup();
+        assert_eq!(d.edges(), de.len());
+    }
+}
+
+#[test]
+fn two_sat() {
+    let rng = Rng::new();
+    for _ in 0..200 {
+        let n = 1 + rng.gen() as usize % 6;
+        let m = rng.gen() as usize % 12;
+        let mut s = TwoSat::new(n);
+        let cls: Vec<_> = (0..m)
+            .map(|_| {
+                let c = (
+                    rng.gen() as usize % n,
+                    rng.gen() % 2 == 0,
+                    rng.gen() as usize % n,
+                    rng.gen() % 2 == 0,
+                );
+                s.add_clause(c.0, c.1, c.2, c.3);
+                c
+            })
+            .collect();
+        let ok = |x: &[bool]| cls.iter().all(|&(a, va, b, vb)| x[a] == va || x[b] == vb);
+        let any = (0..1_usize << n)
+            .any(|mask| ok(&(0..n).map(|i| mask >> i & 1 == 1).collect::<Vec<_>>()));
+        match s.solve() {
+            Some(x) => assert!(ok(&x)),
+            None => assert!(!any),
+        }
+    }
+}
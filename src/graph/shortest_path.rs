@@ -0,0 +1,226 @@
+use super::Csr;
+use crate::core::num::{Integer, Num, Unsigned};
+use crate::ds::RadixHeap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::TryInto;
+
+/// Single source result, `None` for unreachable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPaths<W> {
+    pub dist: Vec<Option<W>>,
+    /// Previous vertex on a shortest path.
+    pub parent: Vec<Option<usize>>,
+}
+
+impl<W: Copy> ShortestPaths<W> {
+    fn new(n: usize, s: usize) -> Self
+    where
+        W: Num,
+    {
+        let mut dist = vec![None; n];
+        dist[s] = Some(W::ZERO);
+        Self {
+            dist,
+            parent: vec![None; n],
+        }
+    }
+    /// Vertices of path from source to `t`.
+    pub fn path(&self, t: usize) -> Option<Vec<usize>> {
+        self.dist[t]?;
+        let mut res = vec![t];
+        let mut u = t;
+        while let Some(p) = self.parent[u] {
+            res.push(p);
+            u = p;
+        }
+        res.reverse();
+        Some(res)
+    }
+    /// Relax `u -> v` with `w`, ret: whether improved.
+    #[inline]
+    fn relax(&mut self, u: usize, v: usize, w: W) -> bool
+    where
+        W: Num + Ord,
+    {
+        let d = match self.dist[u] {
+            Some(d) => d + w,
+            None => return false,
+        };
+        if matches!(self.dist[v], Some(x) if x <= d) {
+            return false;
+        }
+        self.dist[v] = Some(d);
+        self.parent[v] = Some(u);
+        true
+    }
+}
+
+/// Require non-negative weights.
+///
+/// # Time complexity
+///
+/// *O*(*m* log *m*).
+pub fn dijkstra<W>(g: &Csr<W>, s: usize) -> ShortestPaths<W>
+where
+    W: Num + Ord + Copy,
+{
+    let mut res = ShortestPaths::new(g.len(), s);
+    let mut pq = BinaryHeap::new();
+    pq.push(Reverse((W::ZERO, s)));
+    while let Some(Reverse((d, u))) = pq.pop() {
+        if res.dist[u] != Some(d) {
+            continue;
+        }
+        for e in g.adj(u) {
+            debug_assert!(e.w >= W::ZERO);
+            if res.relax(u, e.to, e.w) {
+                pq.push(Reverse((d + e.w, e.to)));
+            }
+        }
+    }
+    res
+}
+
+/// Dijkstra by `RadixHeap`, for unsigned integer weights.
+///
+/// # Time complexity
+///
+/// *O*(*m* + *n* log *C*).
+pub fn dijkstra_radix<W>(g: &Csr<W>, s: usize) -> ShortestPaths<W>
+where
+    W: Integer + Unsigned + Copy + TryInto<u64>,
+{
+    let key = |d: W| d.try_into().ok().expect("weight exceeds u64");
+    let mut res = ShortestPaths::new(g.len(), s);
+    let mut pq = RadixHeap::new();
+    pq.push(0, s);
+    while let Some((d, u)) = pq.pop() {
+        if res.dist[u].map(key) != Some(d) {
+            continue;
+        }
+        for e in g.adj(u) {
+            if res.relax(u, e.to, e.w) {
+                pq.push(key(res.dist[e.to].unwrap()), e.to);
+            }
+        }
+    }
+    res
+}
+
+/// Require weights `0` or `1`.
+///
+/// # Time complexity
+///
+/// *O*(*n* + *m*).
+pub fn bfs01<W>(g: &Csr<W>, s: usize) -> ShortestPaths<W>
+where
+    W: Num + Ord + Copy,
+{
+    let mut res = ShortestPaths::new(g.len(), s);
+    let mut done = vec![false; g.len()];
+    let mut q = VecDeque::new();
+    q.push_back(s);
+    while let Some(u) = q.pop_front() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        for e in g.adj(u) {
+            debug_assert!(e.w == W::ZERO || e.w == W::ONE);
+            if res.relax(u, e.to, e.w) {
+                if e.w == W::ZERO {
+                    q.push_front(e.to);
+                } else {
+                    q.push_back(e.to);
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Allow negative weights, ret: `Err(cycle)` if a negative cycle reachable from `s`.
+///
+/// # Time complexity
+///
+/// *O*(*nm*).
+pub fn bellman_ford<W>(g: &Csr<W>, s: usize) -> Result<ShortestPaths<W>, Vec<usize>>
+where
+    W: Num + Ord + Copy,
+{
+    let n = g.len();
+    let mut res = ShortestPaths::new(n, s);
+    for round in 0..n {
+        let mut last = None;
+        for u in 0..n {
+            for e in g.adj(u) {
+                if res.relax(u, e.to, e.w) {
+                    last = Some(e.to);
+                }
+            }
+        }
+        match last {
+            None => break,
+            Some(mut x) if round + 1 == n => {
+                // still relaxing after n-1 rounds, walk back onto the cycle
+                for _ in 0..n {
+                    x = res.parent[x].unwrap();
+                }
+                let mut cycle = vec![x];
+                let mut u = res.parent[x].unwrap();
+                while u != x {
+                    cycle.push(u);
+                    u = res.parent[u].unwrap();
+                }
+                cycle.reverse();
+                return Err(cycle);
+            }
+            _ => {}
+        }
+    }
+    Ok(res)
+}
+
+/// All pairs, `[i]` for source `i`, ret: `None` if any negative cycle.
+///
+/// # Time complexity
+///
+/// *O*(*n*^3).
+pub fn floyd_warshall<W>(g: &Csr<W>) -> Option<Vec<ShortestPaths<W>>>
+where
+    W: Num + Ord + Copy,
+{
+    let n = g.len();
+    let mut a: Vec<_> = (0..n).map(|s| ShortestPaths::new(n, s)).collect();
+    for (u, a) in a.iter_mut().enumerate() {
+        for e in g.adj(u) {
+            a.relax(u, e.to, e.w);
+        }
+    }
+    for k in 0..n {
+        let ak = a[k].clone();
+        for (i, ai) in a.iter_mut().enumerate() {
+            let dik = match ai.dist[k] {
+                Some(d) => d,
+                None => continue,
+            };
+            for j in 0..n {
+                if let Some(dkj) = ak.dist[j] {
+                    if !matches!(ai.dist[j], Some(x) if x <= dik + dkj) {
+                        ai.dist[j] = Some(dik + dkj);
+                        ai.parent[j] = ak.parent[j];
+                    }
+                }
+            }
+            // stop at once, distances on a negative cycle shrink exponentially
+            if ai.dist[i] < Some(W::ZERO) {
+                return None;
+            }
+        }
+    }
+    Some(a)
+}
+
+#[cfg(test)]
+mod tests;
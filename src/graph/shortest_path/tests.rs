@@ -0,0 +1,121 @@
+use super::*;
+use crate::core::Rng;
+
+fn random_graph(rng: &Rng, n: usize, m: usize, lo: i64, hi: i64) -> Vec<(usize, usize, i64)> {
+    (0..m)
+        .map(|_| {
+            let u = rng.gen() as usize % n;
+            let v = rng.gen() as usize % n;
+            (u, v, lo + (rng.gen() % (hi - lo + 1) as u64) as i64)
+        })
+        .collect()
+}
+
+/// Weight of walk, by cheapest edge between consecutive vertices.
+fn walk_weight(edges: &[(usize, usize, i64)], p: &[usize]) -> i64 {
+    p.windows(2)
+        .map(|e| {
+            edges
+                .iter()
+                .filter(|x| (x.0, x.1) == (e[0], e[1]))
+                .map(|x| x.2)
+                .min()
+                .unwrap()
+        })
+        .sum()
+}
+
+fn check_paths(edges: &[(usize, usize, i64)], s: usize, r: &ShortestPaths<i64>) {
+    for t in 0..r.dist.len() {
+        if let Some(d) = r.dist[t] {
+            let p = r.path(t).unwrap();
+            assert_eq!((p[0], *p.last().unwrap()), (s, t));
+            assert_eq!(walk_weight(edges, &p), d);
+        } else {
+            assert_eq!(r.path(t), None);
+        }
+    }
+}
+
+#[test]
+fn non_negative() {
+    let rng = Rng::new();
+    for _ in 0..30 {
+        let n = 1 + rng.gen() as usize % 15;
+        let edges = random_graph(&rng, n, rng.gen() as usize % 40, 0, 20);
+        let g = Csr::directed(n, &edges);
+        let all = floyd_warshall(&g).unwrap();
+        let gu = Csr::directed(
+            n,
+            &edges
+                .iter()
+                .map(|&(u, v, w)| (u, v, w as u64))
+                .collect::<Vec<_>>(),
+        );
+        for s in 0..n {
+            let d = dijkstra(&g, s);
+            check_paths(&edges, s, &d);
+            assert_eq!(d.dist, all[s].dist);
+            assert_eq!(Ok(d.dist.clone()), bellman_ford(&g, s).map(|r| r.dist));
+            let r = dijkstra_radix(&gu, s);
+            let rd: Vec<_> = r.dist.iter().map(|d| d.map(|x| x as i64)).collect();
+            assert_eq!(rd, d.dist);
+            check_paths(&edges, s, &all[s]);
+        }
+    }
+}
+
+#[test]
+fn zero_one() {
+    let rng = Rng::new();
+    for _ in 0..30 {
+        let n = 1 + rng.gen() as usize % 20;
+        let edges = random_graph(&rng, n, rng.gen() as usize % 50, 0, 1);
+        let g = Csr::undirected(n, &edges);
+        for s in 0..n {
+            let r = bfs01(&g, s);
+            assert_eq!(r.dist, dijkstra(&g, s).dist);
+        }
+    }
+}
+
+#[test]
+fn negative() {
+    let rng = Rng::new();
+    for _ in 0..100 {
+        let n = 1 + rng.gen() as usize % 8;
+        let edges = random_graph(&rng, n, rng.gen() as usize % 15, -5, 20);
+        let g = Csr::directed(n, &edges);
+        let all = floyd_warshall(&g);
+        for s in 0..n {
+            match bellman_ford(&g, s) {
+                Ok(r) => {
+                    check_paths(&edges, s, &r);
+                    if let Some(all) = &all {
+                        assert_eq!(r.dist, all[s].dist);
+                    }
+                }
+                Err(c) => {
+                    assert!(all.is_none());
+                    let mut walk = c.clone();
+                    walk.push(c[0]);
+                    assert!(walk_weight(&edges, &walk) < 0);
+                }
+            }
+        }
+        if all.is_none() {
+            // some source reaches the cycle, e.g. a vertex on it
+            assert!((0..n).any(|s| bellman_ford(&g, s).is_err()));
+        }
+    }
+}
+
+#[test]
+fn floyd_warshall_dense_negative() {
+    let n = 80;
+    let edges: Vec<_> = (0..n)
+        .flat_map(|u| (0..n).filter(move |&v| v != u).map(move |v| (u, v, -1i64)))
+        .collect();
+    assert!(floyd_warshall(&Csr::directed(n, &edges)).is_none());
+    assert!(floyd_warshall(&Csr::directed(2, &[(1, 1, -1i64)])).is_none());
+}
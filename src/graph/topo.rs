@@ -0,0 +1,147 @@
+use super::Csr;
+use crate::core::num::identities::{One, Zero};
+use crate::core::num::Num;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Pending vertices of Kahn's, decides which order comes out.
+trait Frontier: Default {
+    fn push(&mut self, v: usize);
+    fn pop(&mut self) -> Option<usize>;
+}
+impl Frontier for VecDeque<usize> {
+    fn push(&mut self, v: usize) {
+        self.push_back(v);
+    }
+    fn pop(&mut self) -> Option<usize> {
+        self.pop_front()
+    }
+}
+impl Frontier for BinaryHeap<Reverse<usize>> {
+    fn push(&mut self, v: usize) {
+        BinaryHeap::push(self, Reverse(v));
+    }
+    fn pop(&mut self) -> Option<usize> {
+        BinaryHeap::pop(self).map(|v| v.0)
+    }
+}
+
+fn kahn<W: Copy, Q: Frontier>(g: &Csr<W>) -> Result<Vec<usize>, Vec<usize>> {
+    let n = g.len();
+    let mut deg = vec![0; n];
+    for u in 0..n {
+        for e in g.adj(u) {
+            deg[e.to] += 1;
+        }
+    }
+    let mut q = Q::default();
+    for (v, &d) in deg.iter().enumerate() {
+        if d == 0 {
+            q.push(v);
+        }
+    }
+    let mut res = Vec::with_capacity(n);
+    while let Some(u) = q.pop() {
+        res.push(u);
+        for e in g.adj(u) {
+            deg[e.to] -= 1;
+            if deg[e.to] == 0 {
+                q.push(e.to);
+            }
+        }
+    }
+    if res.len() == n {
+        return Ok(res);
+    }
+    // each left vertex has an in-edge from a left one, walk back till repeat
+    let mut pre = vec![n; n];
+    for u in (0..n).filter(|&u| deg[u] > 0) {
+        for e in g.adj(u) {
+            if deg[e.to] > 0 {
+                pre[e.to] = u;
+            }
+        }
+    }
+    let mut vis = vec![false; n];
+    let mut v = (0..n).find(|&v| deg[v] > 0).unwrap();
+    while !vis[v] {
+        vis[v] = true;
+        v = pre[v];
+    }
+    let mut cycle = vec![v];
+    let mut u = pre[v];
+    while u != v {
+        cycle.push(u);
+        u = pre[u];
+    }
+    cycle.reverse();
+    Err(cycle)
+}
+
+/// Kahn's topological order, or `Err(cycle)` with vertices of a directed cycle in order.
+///
+/// # Time complexity
+///
+/// *O*(*n* + *m*).
+pub fn toposort<W: Copy>(g: &Csr<W>) -> Result<Vec<usize>, Vec<usize>> {
+    kahn::<W, VecDeque<usize>>(g)
+}
+
+/// Lexicographically smallest topological order, or `Err(cycle)`.
+///
+/// # Time complexity
+///
+/// *O*(*n* log *n* + *m*).
+pub fn toposort_lex_min<W: Copy>(g: &Csr<W>) -> Result<Vec<usize>, Vec<usize>> {
+    kahn::<W, BinaryHeap<Reverse<usize>>>(g)
+}
+
+/// Longest path of DAG with topological `order`, ret: `(weight, vertices)`, at least one vertex.
+pub fn dag_longest_path<W>(g: &Csr<W>, order: &[usize]) -> (W, Vec<usize>)
+where
+    W: Num + Ord + Copy,
+{
+    let n = g.len();
+    // longest ending at v
+    let mut dp = vec![W::ZERO; n];
+    let mut pre = vec![None; n];
+    for &u in order {
+        for e in g.adj(u) {
+            let x = dp[u] + e.w;
+            if x > dp[e.to] {
+                dp[e.to] = x;
+                pre[e.to] = Some(u);
+            }
+        }
+    }
+    let mut v = match (0..n).max_by_key(|&v| dp[v]) {
+        Some(v) => v,
+        None => return (W::ZERO, vec![]),
+    };
+    let mut path = vec![v];
+    while let Some(u) = pre[v] {
+        path.push(u);
+        v = u;
+    }
+    path.reverse();
+    (dp[path[path.len() - 1]], path)
+}
+
+/// Number of paths from `s` to each vertex of DAG with topological `order`, e.g. over `Mint`.
+pub fn dag_count_paths<T, W>(g: &Csr<W>, order: &[usize], s: usize) -> Vec<T>
+where
+    T: Zero + One + Copy,
+    W: Copy,
+{
+    let mut dp = vec![T::ZERO; g.len()];
+    dp[s] = T::ONE;
+    for &u in order {
+        for e in g.adj(u) {
+            dp[e.to] = dp[e.to] + dp[u];
+        }
+    }
+    dp
+}
+
+#[cfg(test)]
+mod tests;
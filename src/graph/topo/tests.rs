@@ -0,0 +1,100 @@
+use super::*;
+use crate::core::{next_permutation, Rng};
+use crate::define_mint;
+use crate::graph::fixture::random_edges;
+use crate::graph::scc;
+
+fn is_order(n: usize, edges: &[(usize, usize)], o: &[usize]) -> bool {
+    let mut pos = vec![n; n];
+    for (i, &v) in o.iter().enumerate() {
+        pos[v] = i;
+    }
+    o.len() == n && pos.iter().all(|&p| p < n) && edges.iter().all(|&(u, v)| pos[u] < pos[v])
+}
+
+#[test]
+fn order_or_cycle() {
+    let rng = Rng::new();
+    for _ in 0..200 {
+        let n = 1 + rng.gen() as usize % 8;
+        let edges = random_edges(&rng, n, rng.gen() as usize % 12);
+        let g = Csr::directed(n, &edges);
+        let acyclic = {
+            let s = scc(&g);
+            s.count == n && edges.iter().all(|e| e.0 != e.1)
+        };
+        for r in [toposort(&g), toposort_lex_min(&g)].iter() {
+            match r {
+                Ok(o) => assert!(acyclic && is_order(n, &edges, o)),
+                Err(c) => {
+                    assert!(!acyclic);
+                    let mut d = c.clone();
+                    d.sort_unstable();
+                    d.dedup();
+                    assert_eq!(d.len(), c.len());
+                    for i in 0..c.len() {
+                        assert!(edges.contains(&(c[i], c[(i + 1) % c.len()])));
+                    }
+                }
+            }
+        }
+        if acyclic {
+            let mut p: Vec<_> = (0..n).collect();
+            while !is_order(n, &edges, &p) {
+                next_permutation(&mut p);
+            }
+            assert_eq!(toposort_lex_min(&g), Ok(p));
+        }
+    }
+}
+
+#[test]
+fn longest_and_count() {
+    define_mint!(m32, 1_000_000_007, P);
+    let rng = Rng::new();
+    for _ in 0..50 {
+        let n = 1 + rng.gen() as usize % 12;
+        // edges go forward, so acyclic
+        let edges: Vec<_> = random_edges(&rng, n, rng.gen() as usize % 30)
+            .into_iter()
+            .filter(|&(u, v)| u < v)
+            .collect();
+        let we: Vec<_> = edges
+            .iter()
+            .map(|&(u, v)| (u, v, (rng.gen() % 10) as i64))
+            .collect();
+        let g = Csr::directed(n, &we);
+        let o = toposort(&g).unwrap();
+        // brute by dfs over all paths
+        fn dfs(g: &Csr<i64>, u: usize, w: i64, best: &mut i64, cnt: &mut Vec<u64>) {
+            *best = (*best).max(w);
+            cnt[u] += 1;
+            for e in g.adj(u) {
+                dfs(g, e.to, w + e.w, best, cnt);
+            }
+        }
+        let mut best = 0;
+        for s in 0..n {
+            let mut cnt = vec![0; n];
+            dfs(&g, s, 0, &mut best, &mut cnt);
+            let c: Vec<m32> = dag_count_paths(&g, &o, s);
+            assert_eq!(
+                c,
+                cnt.iter().map(|&x| m32::new(x as i32)).collect::<Vec<_>>()
+            );
+        }
+        let (w, p) = dag_longest_path(&g, &o);
+        assert_eq!(w, best);
+        let pw: i64 = p
+            .windows(2)
+            .map(|x| {
+                we.iter()
+                    .filter(|e| (e.0, e.1) == (x[0], x[1]))
+                    .map(|e| e.2)
+                    .max()
+                    .unwrap()
+            })
+            .sum();
+        assert_eq!(pw, w);
+    }
+}
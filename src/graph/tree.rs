@@ -0,0 +1,252 @@
+use super::Csr;
+use crate::algebra::Min;
+use crate::ds::SparseTable;
+
+/// `(parent, depth, order)` of tree `g` rooted at `root`, `order` in dfs pre-order.
+///
+/// Never loops on non-tree input, debug assert instead.
+pub(super) fn dfs_tree<W: Copy>(
+    g: &Csr<W>,
+    root: usize,
+) -> (Vec<Option<usize>>, Vec<usize>, Vec<usize>) {
+    let n = g.len();
+    let mut parent = vec![None; n];
+    let mut depth = vec![0; n];
+    let mut seen = vec![false; n];
+    // id of edge to parent, so that a parallel edge is not mistaken for it
+    let mut up = vec![usize::MAX; n];
+    let mut order = Vec::with_capacity(n);
+    seen[root] = true;
+    let mut st = vec![root];
+    while let Some(u) = st.pop() {
+        order.push(u);
+        for e in g.adj(u) {
+            if e.id == up[u] {
+                continue;
+            }
+            debug_assert!(!seen[e.to], "not a tree");
+            if !seen[e.to] {
+                seen[e.to] = true;
+                parent[e.to] = Some(u);
+                depth[e.to] = depth[u] + 1;
+                up[e.to] = e.id;
+                st.push(e.to);
+            }
+        }
+    }
+    debug_assert_eq!(order.len(), n, "not a tree");
+    (parent, depth, order)
+}
+
+/// Lca backend of `RootedTree`, built from its dfs pre-order.
+pub trait Lca {
+    fn new(parent: &[Option<usize>], depth: &[usize], order: &[usize], tin: &[usize]) -> Self;
+    fn lca(&self, u: usize, v: usize) -> usize;
+    /// `k`-th ancestor, `None` if above root.
+    fn ancestor(&self, u: usize, k: usize) -> Option<usize>;
+}
+
+/// Euler tour by dfs pre-order, `tin` of parents over `order`, min of `(tin[u], tin[v]]` is the lca.
+///
+/// Ancestor by binary search on pre-order of each depth.
+///
+/// # Time complexity
+///
+/// Build *O*(*n* log *n*), `lca` *O*(1), `ancestor` *O*(log *n*).
+pub struct EulerLca {
+    tin: Vec<usize>,
+    order: Vec<usize>,
+    depth: Vec<usize>,
+    st: SparseTable<usize, Min>,
+    /// `tin` of vertices, by depth.
+    layer: Vec<Vec<usize>>,
+}
+
+impl Lca for EulerLca {
+    fn new(parent: &[Option<usize>], depth: &[usize], order: &[usize], tin: &[usize]) -> Self {
+        let a: Vec<_> = order
+            .iter()
+            .map(|&v| parent[v].map_or(0, |p| tin[p]))
+            .collect();
+        let mut layer = vec![];
+        for (i, &v) in order.iter().enumerate() {
+            if layer.len() <= depth[v] {
+                layer.resize(depth[v] + 1, vec![]);
+            }
+            layer[depth[v]].push(i);
+        }
+        Self {
+            tin: tin.to_vec(),
+            order: order.to_vec(),
+            depth: depth.to_vec(),
+            st: SparseTable::new(&a),
+            layer,
+        }
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        if u == v {
+            return u;
+        }
+        let (x, y) = (self.tin[u].min(self.tin[v]), self.tin[u].max(self.tin[v]));
+        self.order[self.st.query(x + 1..y + 1)]
+    }
+    fn ancestor(&self, u: usize, k: usize) -> Option<usize> {
+        if k > self.depth[u] {
+            return None;
+        }
+        // last one at depth entered before u
+        let l = &self.layer[self.depth[u] - k];
+        let i = match l.binary_search(&self.tin[u]) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        Some(self.order[l[i]])
+    }
+}
+
+/// Binary lifting, `up[j][v]` is `2^j`-th ancestor, root to itself.
+///
+/// # Time complexity
+///
+/// Build *O*(*n* log *n*), *O*(log *n*) per query.
+pub struct LiftingLca {
+    depth: Vec<usize>,
+    up: Vec<Vec<usize>>,
+}
+
+impl Lca for LiftingLca {
+    fn new(parent: &[Option<usize>], depth: &[usize], _order: &[usize], _tin: &[usize]) -> Self {
+        let n = parent.len();
+        let mut up = vec![(0..n).map(|v| parent[v].unwrap_or(v)).collect::<Vec<_>>()];
+        while 1 << up.len() < n {
+            let last = up.last().unwrap();
+            let next = (0..n).map(|v| last[last[v]]).collect();
+            up.push(next);
+        }
+        Self {
+            depth: depth.to_vec(),
+            up,
+        }
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] {
+            (v, u)
+        } else {
+            (u, v)
+        };
+        u = self.ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        if u == v {
+            return u;
+        }
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+    fn ancestor(&self, mut u: usize, k: usize) -> Option<usize> {
+        if k > self.depth[u] {
+            return None;
+        }
+        for (j, up) in self.up.iter().enumerate() {
+            if k >> j & 1 == 1 {
+                u = up[u];
+            }
+        }
+        Some(u)
+    }
+}
+
+/// Rooted tree, with lca backend `L`, `EulerLca` by default.
+///
+/// # Example
+///
+/// ```
+/// use cplib::graph::{Csr, LiftingLca, RootedTree};
+///
+/// // 0 - 1 - 2
+/// //   \ 3
+/// let g = Csr::undirected(4, &[(0, 1), (1, 2), (0, 3)]);
+/// let t = RootedTree::new(&g, 0);
+/// assert_eq!(t.lca(2, 3), 0);
+/// assert_eq!(t.jump(2, 3, 2), Some(0));
+/// let t = RootedTree::<LiftingLca>::build(&g, 0);
+/// assert_eq!(t.dist(2, 3), 3);
+/// assert_eq!(t.kth_ancestor(2, 2), Some(0));
+/// ```
+pub struct RootedTree<L = EulerLca> {
+    pub root: usize,
+    pub parent: Vec<Option<usize>>,
+    pub depth: Vec<usize>,
+    /// Dfs pre-order, `order[tin[v]] = v`.
+    pub order: Vec<usize>,
+    pub tin: Vec<usize>,
+    /// Subtree of `v` is `order[tin[v]..tin[v] + size[v]]`.
+    pub size: Vec<usize>,
+    l: L,
+}
+
+impl RootedTree {
+    pub fn new<W: Copy>(g: &Csr<W>, root: usize) -> Self {
+        Self::build(g, root)
+    }
+}
+
+impl<L: Lca> RootedTree<L> {
+    /// `g` must be a tree, undirected.
+    pub fn build<W: Copy>(g: &Csr<W>, root: usize) -> Self {
+        let n = g.len();
+        let (parent, depth, order) = dfs_tree(g, root);
+        let mut tin = vec![0; n];
+        for (i, &v) in order.iter().enumerate() {
+            tin[v] = i;
+        }
+        let mut size = vec![1; n];
+        for &v in order.iter().rev() {
+            if let Some(p) = parent[v] {
+                size[p] += size[v];
+            }
+        }
+        let l = L::new(&parent, &depth, &order, &tin);
+        Self {
+            root,
+            parent,
+            depth,
+            order,
+            tin,
+            size,
+            l,
+        }
+    }
+    /// Whether `u` is ancestor of `v`, including itself.
+    pub fn is_ancestor(&self, u: usize, v: usize) -> bool {
+        self.tin[u] <= self.tin[v] && self.tin[v] < self.tin[u] + self.size[u]
+    }
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        self.l.lca(u, v)
+    }
+    /// Number of edges on path.
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+    pub fn kth_ancestor(&self, u: usize, k: usize) -> Option<usize> {
+        self.l.ancestor(u, k)
+    }
+    /// `k`-th vertex on path `u` to `v`, `0`-th is `u`.
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let w = self.lca(u, v);
+        let (du, dv) = (self.depth[u] - self.depth[w], self.depth[v] - self.depth[w]);
+        if k <= du {
+            self.kth_ancestor(u, k)
+        } else if k <= du + dv {
+            self.kth_ancestor(v, du + dv - k)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
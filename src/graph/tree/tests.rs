@@ -0,0 +1,70 @@
+use super::*;
+use crate::core::Rng;
+use crate::graph::fixture::{brute_path, random_tree};
+
+fn check<L: Lca>(g: &Csr, root: usize) {
+    let t = RootedTree::<L>::build(g, root);
+    let n = g.len();
+    for u in 0..n {
+        for v in 0..n {
+            let p = brute_path(&t.parent, &t.depth, u, v);
+            let w = *p.iter().min_by_key(|&&x| t.depth[x]).unwrap();
+            assert_eq!(t.lca(u, v), w);
+            assert_eq!(t.dist(u, v), p.len() - 1);
+            assert_eq!(t.is_ancestor(u, v), w == u);
+            for k in 0..=p.len() {
+                assert_eq!(t.jump(u, v, k), p.get(k).copied());
+            }
+        }
+        let mut x = Some(u);
+        for k in 0..=t.depth[u] + 1 {
+            assert_eq!(t.kth_ancestor(u, k), x);
+            x = x.and_then(|x| t.parent[x]);
+        }
+    }
+}
+
+#[test]
+fn random() {
+    let rng = Rng::new();
+    for _ in 0..30 {
+        let n = 1 + rng.gen() as usize % 40;
+        let g = random_tree(&rng, n);
+        let root = rng.gen() as usize % n;
+        check::<EulerLca>(&g, root);
+        check::<LiftingLca>(&g, root);
+    }
+}
+
+#[test]
+fn path_graph() {
+    let n = 100;
+    let edges: Vec<_> = (1..n).map(|v| (v - 1, v)).collect();
+    let g = Csr::undirected(n, &edges);
+    check::<EulerLca>(&g, 0);
+    check::<LiftingLca>(&g, 37);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not a tree")]
+fn cycle() {
+    let g = Csr::undirected(3, &[(0, 1), (1, 2), (2, 0)]);
+    RootedTree::new(&g, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not a tree")]
+fn self_loop() {
+    let g = Csr::undirected(2, &[(0, 1), (1, 1)]);
+    RootedTree::new(&g, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not a tree")]
+fn parallel_edge() {
+    let g = Csr::undirected(2, &[(0, 1), (1, 0)]);
+    RootedTree::new(&g, 0);
+}
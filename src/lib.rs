@@ -10,5 +10,6 @@ pub mod algebra;
 pub mod io;
 
 pub mod ds;
+pub mod graph;
 pub mod math;
 pub mod string;
@@ -1,5 +1,5 @@
 use crate::algebra::Min;
-use crate::ds::SparseTable;
+use crate::ds::{RangeQuery, SparseTable};
 
 type P = Vec<usize>;
 
@@ -13,23 +13,35 @@ type P = Vec<usize>;
 /// Query lcp, *O*(1)
 ///
 /// Warning: assume size of alphabet `<=256`.
-pub struct SuffixArray {
+///
+/// The lcp RMQ `Q` defaults to `SparseTable`, opt into `ds::LinearRmq` for *O*(*n*) memory,
+/// by `SuffixArray::<LinearRmq<usize, Min>>::build(s)`.
+pub struct SuffixArray<Q = SparseTable<usize, Min>> {
     /// `sa`: indexes sorted by suffix string.
     pub sa: P,
     /// `rk[i]`: rank of `s[i..]`, note `rk * sa = I`.
     pub rk: P,
     /// `lcp[i] = lcp(rk=i, rk=i+1)`.
     pub lcp: P,
-    st: SparseTable<usize, Min>,
+    st: Q,
 }
 
 impl SuffixArray {
     pub fn new<T>(s: &[T]) -> Self
+    where
+        T: Into<usize> + Copy + PartialEq,
+    {
+        Self::build(s)
+    }
+}
+
+impl<Q: RangeQuery<usize>> SuffixArray<Q> {
+    pub fn build<T>(s: &[T]) -> Self
     where
         T: Into<usize> + Copy + PartialEq,
     {
         let (sa, rk, lcp) = create(s);
-        let st = SparseTable::new(&lcp);
+        let st = Q::build(&lcp);
         Self { sa, rk, lcp, st }
     }
     /// Get lcp of `s[i..]` and `s[j..]`.